@@ -0,0 +1,283 @@
+//! Checks that privileged and user-scoped entry points cannot be invoked
+//! with someone else's authorization.
+//!
+//! Only the chosen caller's signature is mocked for each call. A call that
+//! succeeds while the required signer differs from the caller is an auth bypass.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use hello_world::{
+    BetSide, FaucetConfig, LeaderboardKind, SweepDestination, VirtualTokenContract, VirtualTokenContractClient,
+};
+use libfuzzer_sys::fuzz_target;
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Val, Vec};
+
+#[derive(Arbitrary, Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Admin,
+    Oracle,
+    Alice,
+    Bob,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Call {
+    CreateRound { price: u128, mode: Option<u32> },
+    SetWindows { bet_ledgers: u32, run_ledgers: u32 },
+    SetFeeBps { fee_bps: u32 },
+    SetFaucetConfig { cooldown_ledgers: u32 },
+    SetClaimWindow { ledgers: u32 },
+    SetBetLimits { mode: u32, min_bet: i128 },
+    SweepUnclaimed { round_id: u32, jackpot: bool },
+    StartSeason { prize_pool: i128 },
+    EndSeason,
+    CreateTournament { entry_fee: i128 },
+    FinishTournament { tournament_id: u32 },
+    Mint { to: Role, amount: i128 },
+    Burn { from: Role, amount: i128 },
+    Airdrop { to: Role, amount: i128 },
+    WithdrawTreasury { to: Role, amount: i128 },
+    ResolveRound { price: u128 },
+    PlaceBet { owner: Role, amount: i128, up: bool },
+    WithdrawBet { owner: Role, amount: i128 },
+    PlaceBucketBet { owner: Role, amount: i128, bucket: u32 },
+    CommitPrediction { owner: Role, amount: i128 },
+    JoinTournament { owner: Role, tournament_id: u32 },
+    RegisterReferral { owner: Role, referrer: Role },
+    CreateSyndicate { owner: Role },
+    DepositSyndicate { owner: Role, amount: i128 },
+    WithdrawSyndicate { owner: Role, shares: i128 },
+    SyndicateBet { amount: i128, up: bool },
+    ClaimWinnings { owner: Role },
+    MintInitial { owner: Role },
+    ClaimFaucet { owner: Role },
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    caller: Role,
+    calls: std::vec::Vec<Call>,
+}
+
+struct Actors {
+    admin: Address,
+    oracle: Address,
+    alice: Address,
+    bob: Address,
+}
+
+impl Actors {
+    fn get(&self, role: Role) -> &Address {
+        match role {
+            Role::Admin => &self.admin,
+            Role::Oracle => &self.oracle,
+            Role::Alice => &self.alice,
+            Role::Bob => &self.bob,
+        }
+    }
+}
+
+/// Mocks exactly one authorization from `caller` for `fn_name(args)`
+fn authorize(env: &Env, caller: &Address, contract: &Address, fn_name: &str, args: Vec<Val>) {
+    env.mock_auths(&[MockAuth {
+        address: caller,
+        invoke: &MockAuthInvoke {
+            contract,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+fuzz_target!(|input: Input| {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let actors = Actors {
+        admin: Address::generate(&env),
+        oracle: Address::generate(&env),
+        alice: Address::generate(&env),
+        bob: Address::generate(&env),
+    };
+
+    // Set up legitimately, then restrict auth to the fuzzed caller
+    env.mock_all_auths();
+    client.initialize(&actors.admin, &actors.oracle);
+    client.mint_initial(&actors.alice);
+    client.mint_initial(&actors.bob);
+    // Syndicate 1 is captained by Alice; Bob holds shares in it
+    client.create_syndicate(&actors.alice);
+    client.deposit_syndicate(&actors.bob, &1, &100_0000000);
+
+    let caller = actors.get(input.caller);
+
+    for call in &input.calls {
+        let (required, succeeded) = match call {
+            Call::CreateRound { price, mode } => {
+                authorize(&env, caller, &contract_id, "create_round", (*price, *mode).into_val(&env));
+                (Role::Admin, client.try_create_round(price, mode).is_ok())
+            },
+            Call::SetWindows { bet_ledgers, run_ledgers } => {
+                authorize(&env, caller, &contract_id, "set_windows", (*bet_ledgers, *run_ledgers).into_val(&env));
+                (Role::Admin, client.try_set_windows(bet_ledgers, run_ledgers).is_ok())
+            },
+            Call::SetFeeBps { fee_bps } => {
+                authorize(&env, caller, &contract_id, "set_fee_bps", (*fee_bps,).into_val(&env));
+                (Role::Admin, client.try_set_fee_bps(fee_bps).is_ok())
+            },
+            Call::SetFaucetConfig { cooldown_ledgers } => {
+                let config = FaucetConfig {
+                    floor: 1000_0000000,
+                    cooldown_ledgers: *cooldown_ledgers,
+                    user_daily_cap: 0,
+                    global_daily_cap: 0,
+                };
+                authorize(&env, caller, &contract_id, "set_faucet_config", (config.clone(),).into_val(&env));
+                (Role::Admin, client.try_set_faucet_config(&config).is_ok())
+            },
+            Call::SetClaimWindow { ledgers } => {
+                authorize(&env, caller, &contract_id, "set_claim_window", (*ledgers,).into_val(&env));
+                (Role::Admin, client.try_set_claim_window(ledgers).is_ok())
+            },
+            Call::StartSeason { prize_pool } => {
+                let tiers = Vec::from_array(&env, [10_000u32]);
+                let args = (*prize_pool, tiers.clone(), LeaderboardKind::NetProfit).into_val(&env);
+                authorize(&env, caller, &contract_id, "start_season", args);
+                (Role::Admin, client.try_start_season(prize_pool, &tiers, &LeaderboardKind::NetProfit).is_ok())
+            },
+            Call::EndSeason => {
+                authorize(&env, caller, &contract_id, "end_season", ().into_val(&env));
+                (Role::Admin, client.try_end_season().is_ok())
+            },
+            Call::CreateTournament { entry_fee } => {
+                let tiers = Vec::from_array(&env, [10_000u32]);
+                let args = (*entry_fee, 100i128, 1u32, tiers.clone()).into_val(&env);
+                authorize(&env, caller, &contract_id, "create_tournament", args);
+                (Role::Admin, client.try_create_tournament(entry_fee, &100, &1, &tiers).is_ok())
+            },
+            Call::FinishTournament { tournament_id } => {
+                authorize(&env, caller, &contract_id, "finish_tournament", (*tournament_id,).into_val(&env));
+                (Role::Admin, client.try_finish_tournament(tournament_id).is_ok())
+            },
+            Call::Mint { to, amount } => {
+                let to = actors.get(*to);
+                authorize(&env, caller, &contract_id, "mint", (to.clone(), *amount).into_val(&env));
+                (Role::Admin, client.try_mint(to, amount).is_ok())
+            },
+            Call::Burn { from, amount } => {
+                let from = actors.get(*from);
+                authorize(&env, caller, &contract_id, "burn", (from.clone(), *amount).into_val(&env));
+                (Role::Admin, client.try_burn(from, amount).is_ok())
+            },
+            Call::Airdrop { to, amount } => {
+                let recipients = Vec::from_array(&env, [(actors.get(*to).clone(), *amount)]);
+                authorize(&env, caller, &contract_id, "airdrop", (recipients.clone(),).into_val(&env));
+                (Role::Admin, client.try_airdrop(&recipients).is_ok())
+            },
+            Call::WithdrawTreasury { to, amount } => {
+                let to = actors.get(*to);
+                authorize(&env, caller, &contract_id, "withdraw_treasury", (to.clone(), *amount).into_val(&env));
+                (Role::Admin, client.try_withdraw_treasury(to, amount).is_ok())
+            },
+            Call::SetBetLimits { mode, min_bet } => {
+                authorize(&env, caller, &contract_id, "set_bet_limits", (*mode, *min_bet, 0i128, 0i128).into_val(&env));
+                (Role::Admin, client.try_set_bet_limits(mode, min_bet, &0, &0).is_ok())
+            },
+            Call::SweepUnclaimed { round_id, jackpot } => {
+                let destination = if *jackpot { SweepDestination::Jackpot } else { SweepDestination::Treasury };
+                authorize(&env, caller, &contract_id, "sweep_unclaimed", (*round_id, destination.clone()).into_val(&env));
+                (Role::Admin, client.try_sweep_unclaimed(round_id, &destination).is_ok())
+            },
+            Call::ResolveRound { price } => {
+                authorize(&env, caller, &contract_id, "resolve_round", (*price,).into_val(&env));
+                (Role::Oracle, client.try_resolve_round(price).is_ok())
+            },
+            Call::PlaceBet { owner, amount, up } => {
+                let side = if *up { BetSide::Up } else { BetSide::Down };
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "place_bet", (user.clone(), *amount, side.clone()).into_val(&env));
+                (*owner, client.try_place_bet(user, amount, &side).is_ok())
+            },
+            Call::WithdrawBet { owner, amount } => {
+                let user = actors.get(*owner);
+                let round_id = client.get_active_round().map(|round| round.round_id).unwrap_or(0);
+                authorize(&env, caller, &contract_id, "withdraw_bet", (user.clone(), round_id, *amount).into_val(&env));
+                (*owner, client.try_withdraw_bet(user, &round_id, amount).is_ok())
+            },
+            Call::PlaceBucketBet { owner, amount, bucket } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "place_bucket_bet", (user.clone(), *amount, *bucket).into_val(&env));
+                (*owner, client.try_place_bucket_bet(user, amount, bucket).is_ok())
+            },
+            Call::CommitPrediction { owner, amount } => {
+                let user = actors.get(*owner);
+                let round_id = client.get_active_round().map(|round| round.round_id).unwrap_or(0);
+                let hash = BytesN::from_array(&env, &[0; 32]);
+                authorize(
+                    &env,
+                    caller,
+                    &contract_id,
+                    "commit_prediction",
+                    (user.clone(), round_id, hash.clone(), *amount).into_val(&env),
+                );
+                (*owner, client.try_commit_prediction(user, &round_id, &hash, amount).is_ok())
+            },
+            Call::JoinTournament { owner, tournament_id } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "join_tournament", (user.clone(), *tournament_id).into_val(&env));
+                (*owner, client.try_join_tournament(user, tournament_id).is_ok())
+            },
+            Call::CreateSyndicate { owner } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "create_syndicate", (user.clone(),).into_val(&env));
+                (*owner, client.try_create_syndicate(user).is_ok())
+            },
+            Call::DepositSyndicate { owner, amount } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "deposit_syndicate", (user.clone(), 1u32, *amount).into_val(&env));
+                (*owner, client.try_deposit_syndicate(user, &1, amount).is_ok())
+            },
+            Call::WithdrawSyndicate { owner, shares } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "withdraw_syndicate", (user.clone(), 1u32, *shares).into_val(&env));
+                (*owner, client.try_withdraw_syndicate(user, &1, shares).is_ok())
+            },
+            Call::SyndicateBet { amount, up } => {
+                let side = if *up { BetSide::Up } else { BetSide::Down };
+                authorize(&env, caller, &contract_id, "syndicate_bet", (1u32, *amount, side.clone()).into_val(&env));
+                (Role::Alice, client.try_syndicate_bet(&1, amount, &side).is_ok())
+            },
+            Call::RegisterReferral { owner, referrer } => {
+                let (user, referrer) = (actors.get(*owner), actors.get(*referrer));
+                authorize(&env, caller, &contract_id, "register_referral", (user.clone(), referrer.clone()).into_val(&env));
+                (*owner, client.try_register_referral(user, referrer).is_ok())
+            },
+            Call::ClaimWinnings { owner } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "claim_winnings", (user.clone(),).into_val(&env));
+                (*owner, client.try_claim_winnings(user).is_ok())
+            },
+            Call::ClaimFaucet { owner } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "claim_faucet", (user.clone(),).into_val(&env));
+                (*owner, client.try_claim_faucet(user).is_ok())
+            },
+            Call::MintInitial { owner } => {
+                let user = actors.get(*owner);
+                authorize(&env, caller, &contract_id, "mint_initial", (user.clone(),).into_val(&env));
+                (*owner, client.try_mint_initial(user).is_ok())
+            },
+        };
+
+        if succeeded && required != input.caller {
+            panic!("auth bypass: {:?} succeeded with {:?} signing instead of {:?}", call, input.caller, required);
+        }
+    }
+});
@@ -0,0 +1,57 @@
+//! Seeds extreme balance and pending-winnings values, then claims.
+//!
+//! The public API caps balances at the 1000 vXLM mint, so this target writes
+//! storage directly to reach the `i128` edges that a long-lived account or a
+//! future minting path could hit. Claiming must either credit exactly the
+//! pending amount or fail with a `ContractError` — never abort.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use hello_world::{DataKey, VirtualTokenContract, VirtualTokenContractClient};
+use libfuzzer_sys::fuzz_target;
+use soroban_sdk::testutils::{Address as _, EnvTestConfig};
+use soroban_sdk::{Address, Env};
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    balance: i128,
+    pending: i128,
+}
+
+fuzz_target!(|input: Input| {
+    // Only states the contract itself could produce are interesting
+    if input.balance < 0 || input.pending < 0 {
+        return;
+    }
+
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Balance(user.clone()), &input.balance);
+        storage.set(&DataKey::PendingWinnings(user.clone()), &input.pending);
+    });
+
+    match client.try_claim_winnings(&user) {
+        Ok(Ok(claimed)) => {
+            assert_eq!(claimed, input.pending);
+            assert_eq!(Some(client.balance(&user)), input.balance.checked_add(input.pending));
+            assert_eq!(client.get_pending_winnings(&user), 0);
+        },
+        Ok(Err(err)) => panic!("claim result failed to convert: {:?}", err),
+        Err(Ok(_)) => {
+            // Rejected with a contract error: nothing may have moved
+            assert_eq!(client.balance(&user), input.balance);
+            assert_eq!(client.get_pending_winnings(&user), input.pending);
+        },
+        Err(Err(err)) => panic!("claim aborted with host error {:?} for {:?}", err, input),
+    }
+});
@@ -0,0 +1,300 @@
+//! Drives every public entry point with arbitrary arguments.
+//!
+//! Any call may fail with a `ContractError`; a host-level error (panic,
+//! arithmetic overflow, storage failure) is treated as a finding.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use hello_world::{
+    BetSide, FaucetConfig, LeaderboardKind, PenaltyDestination, PrecisionPayout, PrecisionTieRules, RemainderPolicy, Scoring,
+    ScoringKernel, StakeWeighting, SweepDestination, Tolerance, VirtualTokenContract, VirtualTokenContractClient,
+};
+use libfuzzer_sys::fuzz_target;
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger as _};
+use soroban_sdk::xdr::ScErrorType;
+use soroban_sdk::{Address, BytesN, Env, Error};
+
+const USERS: usize = 4;
+
+#[derive(Arbitrary, Debug)]
+enum Step {
+    MintInitial { user: u8 },
+    ClaimFaucet { user: u8 },
+    SetFaucetConfig { floor: i128, cooldown_ledgers: u32, user_daily_cap: i128, global_daily_cap: i128 },
+    Mint { user: u8, amount: i128 },
+    Burn { user: u8, amount: i128 },
+    Airdrop { recipients: Vec<(u8, i128)> },
+    CreateRound { price: u128, mode: Option<u32> },
+    SetWindows { bet_ledgers: u32, run_ledgers: u32 },
+    SetFeeBps { fee_bps: u32 },
+    WithdrawTreasury { user: u8, amount: i128 },
+    SetReferralBps { referral_bps: u32 },
+    RegisterReferral { user: u8, referrer: u8 },
+    SetClaimWindow { ledgers: u32 },
+    SetExitPenalty { penalty_bps: u32, to_pool: bool },
+    SetBetLimits { mode: u32, min_bet: i128, max_bet_per_user: i128, max_pool_size: i128 },
+    SetBucketBounds { bounds: Vec<i32> },
+    SetPrecisionPayout { stake_weighted: bool, max_win_bps: u32, refund: bool, tiers: Option<Vec<u32>> },
+    SetScoredPayout { gaussian: bool, width: u128 },
+    SetRevealWindow { ledgers: u32 },
+    SetPrecisionTieRules { tolerance: u8, band: u32, bonus_multiplier_bps: u32, bonus_pool_bps: u32 },
+    PlaceBet { user: u8, amount: i128, up: bool },
+    PlaceBucketBet { user: u8, amount: i128, bucket: u32 },
+    PlacePrecisionPrediction { user: u8, amount: i128, price: u128 },
+    CommitPrediction { user: u8, round_id: u32, amount: i128, price: u128, salt: u8 },
+    RevealPrediction { user: u8, round_id: u32, price: u128, salt: u8 },
+    IncreaseBet { user: u8, round_id: u32, amount: i128 },
+    WithdrawBet { user: u8, round_id: u32, amount: i128 },
+    QuoteBet { round_id: u32, amount: i128, up: bool },
+    GetRoundOdds { round_id: u32 },
+    ResolveRound { price: u128 },
+    ClaimWinnings { user: u8 },
+    ClaimRound { user: u8, round_id: u32 },
+    ClaimAll { user: u8, max_rounds: u32 },
+    SweepUnclaimed { round_id: u32, jackpot: bool },
+    StartSeason { prize_pool: i128, tiers: Vec<u32>, ranking: u8 },
+    EndSeason,
+    CreateTournament { entry_fee: i128, starting_chips: i128, rounds: u32, tiers: Vec<u32> },
+    JoinTournament { user: u8, tournament_id: u32 },
+    CreateTournamentRound { tournament_id: u32, price: u128, mode: Option<u32> },
+    FinishTournament { tournament_id: u32 },
+    CreateSyndicate { user: u8 },
+    DepositSyndicate { user: u8, syndicate_id: u32, amount: i128 },
+    WithdrawSyndicate { user: u8, syndicate_id: u32, shares: i128 },
+    SyndicateBet { syndicate_id: u32, amount: i128, up: bool },
+    SettleSyndicate { syndicate_id: u32 },
+    AdvanceLedger { ledgers: u16 },
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    steps: Vec<Step>,
+}
+
+/// Fails the run when a call aborted in the host instead of returning a contract error
+fn check<T, E, C>(step: &Step, result: Result<T, Result<E, C>>)
+where
+    E: Into<Error>,
+    C: core::fmt::Debug,
+{
+    match result {
+        Err(Ok(err)) => {
+            // Entry points without a Result return surface aborts as a plain `Error`
+            let err: Error = err.into();
+            if !err.is_type(ScErrorType::Contract) {
+                panic!("{:?} aborted with host error {:?}", step, err);
+            }
+        },
+        Err(Err(err)) => panic!("{:?} aborted with host error {:?}", step, err),
+        Ok(_) => {},
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let users: Vec<Address> = (0..USERS).map(|_| Address::generate(&env)).collect();
+    let user = |index: u8| &users[index as usize % USERS];
+
+    client.initialize(&admin, &oracle);
+
+    for step in &input.steps {
+        match step {
+            Step::MintInitial { user: u } => check(step, client.try_mint_initial(user(*u))),
+            Step::ClaimFaucet { user: u } => check(step, client.try_claim_faucet(user(*u))),
+            Step::SetFaucetConfig { floor, cooldown_ledgers, user_daily_cap, global_daily_cap } => {
+                let config = FaucetConfig {
+                    floor: *floor,
+                    cooldown_ledgers: *cooldown_ledgers,
+                    user_daily_cap: *user_daily_cap,
+                    global_daily_cap: *global_daily_cap,
+                };
+                check(step, client.try_set_faucet_config(&config))
+            },
+            Step::Mint { user: u, amount } => check(step, client.try_mint(user(*u), amount)),
+            Step::Burn { user: u, amount } => check(step, client.try_burn(user(*u), amount)),
+            Step::Airdrop { recipients } => {
+                let mut list = soroban_sdk::Vec::new(&env);
+                for (u, amount) in recipients {
+                    list.push_back((user(*u).clone(), *amount));
+                }
+                check(step, client.try_airdrop(&list))
+            },
+            Step::CreateRound { price, mode } => check(step, client.try_create_round(price, mode)),
+            Step::SetWindows { bet_ledgers, run_ledgers } => {
+                check(step, client.try_set_windows(bet_ledgers, run_ledgers))
+            },
+            Step::SetFeeBps { fee_bps } => check(step, client.try_set_fee_bps(fee_bps)),
+            Step::WithdrawTreasury { user: u, amount } => {
+                check(step, client.try_withdraw_treasury(user(*u), amount))
+            },
+            Step::SetReferralBps { referral_bps } => check(step, client.try_set_referral_bps(referral_bps)),
+            Step::RegisterReferral { user: u, referrer } => {
+                check(step, client.try_register_referral(user(*u), user(*referrer)))
+            },
+            Step::SetClaimWindow { ledgers } => check(step, client.try_set_claim_window(ledgers)),
+            Step::SetExitPenalty { penalty_bps, to_pool } => {
+                let destination = if *to_pool { PenaltyDestination::Pool } else { PenaltyDestination::Treasury };
+                check(step, client.try_set_exit_penalty(penalty_bps, &destination))
+            },
+            Step::SetBetLimits { mode, min_bet, max_bet_per_user, max_pool_size } => {
+                check(step, client.try_set_bet_limits(mode, min_bet, max_bet_per_user, max_pool_size))
+            },
+            Step::SetBucketBounds { bounds } => {
+                let bounds = soroban_sdk::Vec::from_slice(&env, bounds);
+                check(step, client.try_set_bucket_bounds(&bounds))
+            },
+            Step::SetPrecisionPayout { stake_weighted, max_win_bps, refund, tiers } => {
+                let payout = if let Some(tiers) = tiers {
+                    PrecisionPayout::Tiered(soroban_sdk::Vec::from_slice(&env, tiers))
+                } else if *stake_weighted {
+                    let remainder = if *refund { RemainderPolicy::Refund } else { RemainderPolicy::Rollover };
+                    PrecisionPayout::StakeWeighted(StakeWeighting { max_win_bps: *max_win_bps, remainder })
+                } else {
+                    PrecisionPayout::Even
+                };
+                check(step, client.try_set_precision_payout(&payout))
+            },
+            Step::SetScoredPayout { gaussian, width } => {
+                let kernel = if *gaussian { ScoringKernel::Gaussian } else { ScoringKernel::InverseDistance };
+                let payout = PrecisionPayout::Scored(Scoring { kernel, width: *width });
+                check(step, client.try_set_precision_payout(&payout))
+            },
+            Step::SetRevealWindow { ledgers } => check(step, client.try_set_reveal_window(ledgers)),
+            Step::SetPrecisionTieRules { tolerance, band, bonus_multiplier_bps, bonus_pool_bps } => {
+                let tolerance = match tolerance % 3 {
+                    0 => Tolerance::Exact,
+                    1 => Tolerance::Units(*band as u128),
+                    _ => Tolerance::Bps(*band),
+                };
+                let rules = PrecisionTieRules {
+                    tolerance,
+                    bonus_multiplier_bps: *bonus_multiplier_bps,
+                    bonus_pool_bps: *bonus_pool_bps,
+                };
+                check(step, client.try_set_precision_tie_rules(&rules))
+            },
+            Step::PlaceBucketBet { user: u, amount, bucket } => {
+                check(step, client.try_place_bucket_bet(user(*u), amount, bucket))
+            },
+            Step::PlaceBet { user: u, amount, up } => {
+                let side = if *up { BetSide::Up } else { BetSide::Down };
+                check(step, client.try_place_bet(user(*u), amount, &side))
+            },
+            Step::PlacePrecisionPrediction { user: u, amount, price } => {
+                check(step, client.try_place_precision_prediction(user(*u), amount, price))
+            },
+            Step::CommitPrediction { user: u, round_id, amount, price, salt } => {
+                let hash = client.compute_commitment(user(*u), price, &BytesN::from_array(&env, &[*salt; 32]));
+                check(step, client.try_commit_prediction(user(*u), round_id, &hash, amount))
+            },
+            Step::RevealPrediction { user: u, round_id, price, salt } => {
+                check(step, client.try_reveal_prediction(user(*u), round_id, price, &BytesN::from_array(&env, &[*salt; 32])))
+            },
+            Step::IncreaseBet { user: u, round_id, amount } => {
+                check(step, client.try_increase_bet(user(*u), round_id, amount))
+            },
+            Step::WithdrawBet { user: u, round_id, amount } => {
+                check(step, client.try_withdraw_bet(user(*u), round_id, amount))
+            },
+            Step::QuoteBet { round_id, amount, up } => {
+                let side = if *up { BetSide::Up } else { BetSide::Down };
+                check(step, client.try_quote_bet(round_id, &side, amount))
+            },
+            Step::GetRoundOdds { round_id } => check(step, client.try_get_round_odds(round_id)),
+            Step::ResolveRound { price } => check(step, client.try_resolve_round(price)),
+            Step::ClaimWinnings { user: u } => check(step, client.try_claim_winnings(user(*u))),
+            Step::ClaimRound { user: u, round_id } => check(step, client.try_claim_round(user(*u), round_id)),
+            Step::ClaimAll { user: u, max_rounds } => check(step, client.try_claim_all(user(*u), max_rounds)),
+            Step::StartSeason { prize_pool, tiers, ranking } => {
+                let ranking = match ranking % 3 {
+                    0 => LeaderboardKind::NetProfit,
+                    1 => LeaderboardKind::BestStreak,
+                    _ => LeaderboardKind::PrecisionAccuracy,
+                };
+                let tiers = soroban_sdk::Vec::from_slice(&env, tiers);
+                check(step, client.try_start_season(prize_pool, &tiers, &ranking))
+            },
+            Step::EndSeason => check(step, client.try_end_season()),
+            Step::CreateTournament { entry_fee, starting_chips, rounds, tiers } => {
+                let tiers = soroban_sdk::Vec::from_slice(&env, tiers);
+                check(step, client.try_create_tournament(entry_fee, starting_chips, rounds, &tiers))
+            },
+            Step::JoinTournament { user: u, tournament_id } => {
+                check(step, client.try_join_tournament(user(*u), tournament_id))
+            },
+            Step::CreateSyndicate { user: u } => check(step, client.try_create_syndicate(user(*u))),
+            Step::DepositSyndicate { user: u, syndicate_id, amount } => {
+                check(step, client.try_deposit_syndicate(user(*u), syndicate_id, amount))
+            },
+            Step::WithdrawSyndicate { user: u, syndicate_id, shares } => {
+                check(step, client.try_withdraw_syndicate(user(*u), syndicate_id, shares))
+            },
+            Step::SyndicateBet { syndicate_id, amount, up } => {
+                let side = if *up { BetSide::Up } else { BetSide::Down };
+                check(step, client.try_syndicate_bet(syndicate_id, amount, &side))
+            },
+            Step::SettleSyndicate { syndicate_id } => check(step, client.try_settle_syndicate(syndicate_id)),
+            Step::CreateTournamentRound { tournament_id, price, mode } => {
+                check(step, client.try_create_tournament_round(tournament_id, price, mode))
+            },
+            Step::FinishTournament { tournament_id } => check(step, client.try_finish_tournament(tournament_id)),
+            Step::SweepUnclaimed { round_id, jackpot } => {
+                let destination = if *jackpot { SweepDestination::Jackpot } else { SweepDestination::Treasury };
+                check(step, client.try_sweep_unclaimed(round_id, &destination))
+            },
+            Step::AdvanceLedger { ledgers } => {
+                env.ledger().with_mut(|li| {
+                    li.sequence_number = li.sequence_number.saturating_add(*ledgers as u32);
+                });
+            },
+        }
+
+        for u in &users {
+            assert!(client.balance(u) >= 0, "negative balance after {:?}", step);
+            assert!(client.get_pending_winnings(u) >= 0, "negative pending after {:?}", step);
+            assert!(client.get_achievements(u).len() <= 5, "duplicate badge after {:?}", step);
+            for tournament_id in 1..=3 {
+                assert!(client.get_tournament_chips(&tournament_id, u) >= 0, "negative chips after {:?}", step);
+            }
+        }
+        for syndicate_id in 1..=3 {
+            if let Some(syndicate) = client.get_syndicate(&syndicate_id) {
+                let held: i128 = syndicate
+                    .members
+                    .iter()
+                    .map(|member| client.get_syndicate_shares(&syndicate_id, &member))
+                    .sum();
+                assert_eq!(held, syndicate.total_shares, "syndicate shares do not add up after {:?}", step);
+                assert!(client.balance(&syndicate.account) >= 0, "negative syndicate pool after {:?}", step);
+            }
+        }
+        assert!(client.get_total_supply() >= 0, "negative supply after {:?}", step);
+        let supply = client.get_supply_breakdown();
+        assert_eq!(
+            supply.circulating + supply.staked + supply.pending + supply.treasury + supply.jackpot
+                + supply.tournament_pools + supply.dust,
+            supply.total_supply,
+            "supply breakdown does not sum after {:?}",
+            step
+        );
+        assert!(client.get_treasury() >= 0, "negative treasury after {:?}", step);
+        assert!(client.get_jackpot() >= 0, "negative jackpot after {:?}", step);
+
+        let board = client.get_leaderboard(&LeaderboardKind::NetProfit, &u32::MAX);
+        assert!(board.len() <= 10, "oversized leaderboard after {:?}", step);
+        for i in 1..board.len() {
+            let (above, below) = (board.get(i - 1).unwrap(), board.get(i).unwrap());
+            assert!(above.score >= below.score, "unsorted leaderboard after {:?}", step);
+        }
+    }
+});
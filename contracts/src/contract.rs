@@ -1,9 +1,63 @@
 //! Core contract implementation for the XLM Price Prediction Market.
 
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Map, Vec};
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, Vec};
 
 use crate::errors::ContractError;
-use crate::types::{BetSide, DataKey, PrecisionPrediction, Round, RoundMode, UserPosition, UserStats};
+use crate::types::{
+    Achievement, AchievementKind, BetLimits, BetQuote, BetSide, BucketPosition, ContractConfig, DataKey,
+    ExpiringClaim, FaucetClaim, FaucetConfig, FaucetEmission, LeaderboardEntry, LeaderboardKind,
+    LegacyUserStats, PenaltyDestination, PrecisionCommit, PrecisionPayout, PrecisionPrediction, PrecisionRank,
+    PrecisionTieRules, ReferralStats, RemainderPolicy, Round, RoundClaim, RoundMode, RoundOdds, RoundOutcome,
+    RoundResult, Scoring, ScoringKernel, Season, SeasonKey, StakeWeighting, SupplyBreakdown, SweepDestination,
+    Syndicate, SyndicateKey, TokenKey, Tolerance, Tournament, TournamentStanding, TournamentStatus,
+    UserPosition, UserStats,
+};
+
+/// Most bucket boundaries a Buckets round may have (10 buckets)
+const MAX_BUCKET_BOUNDS: u32 = 9;
+
+/// Highest Buckets start price: the price change in bps must fit in an i128
+const MAX_BUCKETS_START_PRICE: u128 = (i128::MAX / 10_000) as u128;
+
+/// Most ranks a tiered Precision payout table may pay
+const MAX_PAYOUT_TIERS: u32 = 10;
+
+/// Fixed-point scale for scored Precision payouts (1.0 = 1_000_000)
+const SCORE_SCALE: i128 = 1_000_000;
+
+/// e^-1 at SCORE_SCALE
+const EXP_NEG_ONE: i128 = 367_879;
+
+/// Largest scoring width: the full 4-decimal price range
+const MAX_SCORING_WIDTH: u128 = 99_999_999;
+
+/// Widest Precision tie band in price units: the full 4-decimal price range
+const MAX_TOLERANCE_UNITS: u128 = 99_999_999;
+
+/// Entries shown on each leaderboard
+const LEADERBOARD_SIZE: u32 = 10;
+
+/// Ranked candidates stored behind each leaderboard, so users below the top entries can be
+/// promoted when a listed user's score falls
+const LEADERBOARD_CANDIDATES: u32 = 4 * LEADERBOARD_SIZE;
+
+/// Settled Precision rounds needed before a user appears on the accuracy leaderboard
+const MIN_ACCURACY_ROUNDS: u32 = 3;
+
+/// Ledgers in a faucet day (~5s per ledger)
+const LEDGERS_PER_DAY: u32 = 17_280;
+
+/// Balance the faucet tops users up to unless configured otherwise
+const DEFAULT_FAUCET_FLOOR: i128 = 1000_0000000;
+
+/// Most players a tournament may take
+const MAX_TOURNAMENT_PLAYERS: u32 = 50;
+
+/// Most recipients a single airdrop may credit
+const MAX_AIRDROP_RECIPIENTS: u32 = 100;
+
+/// Most members a syndicate may have
+const MAX_SYNDICATE_MEMBERS: u32 = 50;
 
 #[contract]
 pub struct VirtualTokenContract;
@@ -29,7 +83,7 @@ impl VirtualTokenContract {
     }
     
     /// Creates a new prediction round (admin only)
-    /// mode: 0 = Up/Down (default), 1 = Precision (Legends)
+    /// mode: 0 = Up/Down (default), 1 = Precision (Legends), 2 = Buckets
     pub fn create_round(env: Env, start_price: u128, mode: Option<u32>) -> Result<(), ContractError> {
         if start_price == 0 {
             return Err(ContractError::InvalidPrice);
@@ -37,17 +91,7 @@ impl VirtualTokenContract {
 
         // Default to Up/Down mode (0) if not specified
         let mode_value = mode.unwrap_or(0);
-
-        // Validate mode is either 0 or 1
-        if mode_value > 1 {
-            return Err(ContractError::InvalidMode);
-        }
-
-        let round_mode = if mode_value == 0 {
-            RoundMode::UpDown
-        } else {
-            RoundMode::Precision
-        };
+        let round_mode = Self::_parse_mode(mode_value)?;
 
         let admin: Address = env.storage()
             .persistent()
@@ -56,6 +100,27 @@ impl VirtualTokenContract {
 
         admin.require_auth();
 
+        Self::_open_round(&env, start_price, round_mode, mode_value, None)
+    }
+
+    /// Opens a new round, drawing its jackpot from the tournament it belongs to, if any
+    fn _open_round(
+        env: &Env,
+        start_price: u128,
+        round_mode: RoundMode,
+        mode_value: u32,
+        tournament_id: Option<u32>,
+    ) -> Result<(), ContractError> {
+        // A tournament round holds the players' chips until it resolves, so it is never replaced
+        if env.storage().persistent().has(&DataKey::ActiveTournamentRound) {
+            return Err(ContractError::TournamentNotOver);
+        }
+
+        // Larger start prices could never be resolved into a bucket
+        if round_mode == RoundMode::Buckets && start_price > MAX_BUCKETS_START_PRICE {
+            return Err(ContractError::InvalidParameters);
+        }
+
         // Get configured windows (with defaults)
         let bet_ledgers: u32 = env.storage()
             .persistent()
@@ -74,14 +139,76 @@ impl VirtualTokenContract {
             .checked_add(run_ledgers)
             .ok_or(ContractError::Overflow)?;
 
+        // Sealed predictions can be revealed until the reveal window closes (end_ledger if unset)
+        let reveal_end_ledger = match Self::get_reveal_window(env.clone()) {
+            Some(reveal_ledgers) => bet_end_ledger
+                .checked_add(reveal_ledgers)
+                .ok_or(ContractError::Overflow)?
+                .min(end_ledger),
+            None => end_ledger,
+        };
+
+        // Stakes left in a round that is replaced without being resolved are lost
+        if let Some(previous) = env.storage().persistent().get::<_, Round>(&DataKey::ActiveRound) {
+            Self::_adjust_supply(env, TokenKey::Dust, Self::_round_stake(env, &previous)?)?;
+            env.storage().persistent().remove(&DataKey::Syndicate(SyndicateKey::Betting));
+        }
+
+        let last_round_id: u32 = env.storage()
+            .persistent()
+            .get(&DataKey::RoundCounter)
+            .unwrap_or(0);
+        let round_id = last_round_id
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::RoundCounter, &round_id);
+
+        // Swept funds waiting in the jackpot boost this round's payout
+        let jackpot = match tournament_id {
+            Some(tournament_id) => {
+                let mut tournament = Self::_tournament(env, tournament_id)?;
+                let jackpot = tournament.jackpot;
+                tournament.jackpot = 0;
+                env.storage().persistent().set(&DataKey::Tournament(tournament_id), &tournament);
+                env.storage().persistent().set(&DataKey::ActiveTournamentRound, &tournament_id);
+                jackpot
+            },
+            None => {
+                let jackpot: i128 = env.storage().persistent().get(&DataKey::Jackpot).unwrap_or(0);
+                env.storage().persistent().remove(&DataKey::Jackpot);
+                jackpot
+            },
+        };
+
+        // Buckets rounds keep the bounds configured at creation
+        let bucket_bounds = if round_mode == RoundMode::Buckets {
+            Self::get_bucket_bounds(env.clone())
+        } else {
+            Vec::new(env)
+        };
+        let mut bucket_pools = Vec::new(env);
+        if round_mode == RoundMode::Buckets {
+            for _ in 0..=bucket_bounds.len() {
+                bucket_pools.push_back(0i128);
+            }
+        }
+
         let round = Round {
+            round_id,
             price_start: start_price,
             start_ledger,
             bet_end_ledger,
+            reveal_end_ledger,
             end_ledger,
             pool_up: 0,
             pool_down: 0,
             mode: round_mode.clone(),
+            jackpot,
+            bucket_bounds,
+            bucket_pools,
+            precision_payout: Self::get_precision_payout(env.clone()),
+            tie_rules: Self::get_precision_tie_rules(env.clone()),
+            tournament_id,
         };
 
         env.storage().persistent().set(&DataKey::ActiveRound, &round);
@@ -89,6 +216,8 @@ impl VirtualTokenContract {
         // Clear previous round's positions based on mode
         env.storage().persistent().remove(&DataKey::UpDownPositions);
         env.storage().persistent().remove(&DataKey::PrecisionPositions);
+        env.storage().persistent().remove(&DataKey::BucketPositions);
+        env.storage().persistent().remove(&DataKey::PrecisionCommits);
 
         // Emit round creation event with mode
         #[allow(deprecated)]
@@ -133,6 +262,13 @@ impl VirtualTokenContract {
         if bet_ledgers >= run_ledgers {
             return Err(ContractError::InvalidDuration);
         }
+
+        // Reveal window must still close by the end of the round
+        if let Some(reveal_ledgers) = Self::get_reveal_window(env.clone()) {
+            if bet_ledgers.saturating_add(reveal_ledgers) > run_ledgers {
+                return Err(ContractError::InvalidDuration);
+            }
+        }
         
         env.storage().persistent().set(&DataKey::BetWindowLedgers, &bet_ledgers);
         env.storage().persistent().set(&DataKey::RunWindowLedgers, &run_ledgers);
@@ -146,533 +282,3985 @@ impl VirtualTokenContract {
         
         Ok(())
     }
-    
-    /// Returns user statistics (wins, losses, streaks)
-    pub fn get_user_stats(env: Env, user: Address) -> UserStats {
-        let key = DataKey::UserStats(user);
-        env.storage().persistent().get(&key).unwrap_or(UserStats {
-            total_wins: 0,
-            total_losses: 0,
-            current_streak: 0,
-            best_streak: 0,
-        })
-    }
-    
-    /// Returns user's claimable winnings
-    pub fn get_pending_winnings(env: Env, user: Address) -> i128 {
-        let key = DataKey::PendingWinnings(user);
-        env.storage().persistent().get(&key).unwrap_or(0)
-    }
-    
-    /// Places a bet on the active round (Up/Down mode only)
-    pub fn place_bet(env: Env, user: Address, amount: i128, side: BetSide) -> Result<(), ContractError> {
-        user.require_auth();
 
-        if amount <= 0 {
-            return Err(ContractError::InvalidBetAmount);
+    /// Sets how many ledgers after betting closes sealed predictions can be revealed (admin only)
+    /// 0 removes the limit so reveals are accepted until end_ledger
+    pub fn set_reveal_window(env: Env, reveal_ledgers: u32) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if reveal_ledgers == 0 {
+            env.storage().persistent().remove(&DataKey::RevealWindowLedgers);
+        } else {
+            let bet_ledgers: u32 = env.storage()
+                .persistent()
+                .get(&DataKey::BetWindowLedgers)
+                .unwrap_or(6);
+            let run_ledgers: u32 = env.storage()
+                .persistent()
+                .get(&DataKey::RunWindowLedgers)
+                .unwrap_or(12);
+            if bet_ledgers.saturating_add(reveal_ledgers) > run_ledgers {
+                return Err(ContractError::InvalidDuration);
+            }
+            env.storage().persistent().set(&DataKey::RevealWindowLedgers, &reveal_ledgers);
         }
 
-        let mut round: Round = env.storage()
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("reveal"), symbol_short!("updated")),
+            reveal_ledgers,
+        );
+
+        Ok(())
+    }
+
+    /// Returns the reveal window in ledgers, if one is configured
+    pub fn get_reveal_window(env: Env) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::RevealWindowLedgers)
+    }
+
+    /// Sets the protocol fee in basis points (admin only)
+    /// The fee is taken from the losing side's stakes and credited to the treasury
+    pub fn set_fee_bps(env: Env, fee_bps: u32) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
             .persistent()
-            .get(&DataKey::ActiveRound)
-            .ok_or(ContractError::NoActiveRound)?;
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
 
-        // Verify round is in Up/Down mode
-        if round.mode != RoundMode::UpDown {
-            return Err(ContractError::WrongModeForPrediction);
-        }
+        admin.require_auth();
 
-        let current_ledger = env.ledger().sequence();
-        if current_ledger >= round.bet_end_ledger {
-            return Err(ContractError::RoundEnded);
+        if fee_bps > 10_000 {
+            return Err(ContractError::InvalidFee);
         }
 
-        let user_balance = Self::balance(env.clone(), user.clone());
-        if user_balance < amount {
-            return Err(ContractError::InsufficientBalance);
-        }
+        env.storage().persistent().set(&DataKey::FeeBps, &fee_bps);
 
-        // Use UpDownPositions storage for Up/Down mode
-        let mut positions: Map<Address, UserPosition> = env.storage()
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("fee"), symbol_short!("updated")),
+            fee_bps,
+        );
+
+        Ok(())
+    }
+
+    /// Returns the protocol fee in basis points (0 if never set)
+    pub fn get_fee_bps(env: Env) -> u32 {
+        env.storage().persistent().get(&DataKey::FeeBps).unwrap_or(0)
+    }
+
+    /// Returns the treasury balance: protocol fees and other credits, less withdrawals
+    pub fn get_treasury(env: Env) -> i128 {
+        env.storage().persistent().get(&DataKey::Treasury).unwrap_or(0)
+    }
+
+    /// Pays `amount` from the treasury into `to`'s balance (admin only)
+    /// Returns the treasury balance left
+    pub fn withdraw_treasury(env: Env, to: Address, amount: i128) -> Result<i128, ContractError> {
+        let admin: Address = env.storage()
             .persistent()
-            .get(&DataKey::UpDownPositions)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
 
-        if positions.contains_key(user.clone()) {
-            return Err(ContractError::AlreadyBet);
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidParameters);
         }
 
-        let new_balance = user_balance
+        let treasury = Self::get_treasury(env.clone());
+        if treasury < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+        let remaining = treasury
             .checked_sub(amount)
             .ok_or(ContractError::Overflow)?;
-        Self::_set_balance(&env, user.clone(), new_balance);
+        env.storage().persistent().set(&DataKey::Treasury, &remaining);
 
-        let position = UserPosition {
-            amount,
-            side: side.clone(),
-        };
-        positions.set(user.clone(), position);
+        let balance = Self::balance(env.clone(), to.clone())
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(&env, to.clone(), balance)?;
 
-        match side {
-            BetSide::Up => {
-                round.pool_up = round.pool_up
-                    .checked_add(amount)
-                    .ok_or(ContractError::Overflow)?;
-            },
-            BetSide::Down => {
-                round.pool_down = round.pool_down
-                    .checked_add(amount)
-                    .ok_or(ContractError::Overflow)?;
-            },
-        }
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("treasury"), symbol_short!("withdraw")),
+            (to, amount),
+        );
 
-        env.storage().persistent().set(&DataKey::UpDownPositions, &positions);
-        env.storage().persistent().set(&DataKey::ActiveRound, &round);
+        Ok(remaining)
+    }
 
-        // Also keep legacy Positions storage for backwards compatibility
-        let mut legacy_positions: Map<Address, UserPosition> = env.storage()
+    /// Sets the share of the protocol fee paid to referrers, in basis points of the fee (admin only)
+    pub fn set_referral_bps(env: Env, referral_bps: u32) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
             .persistent()
-            .get(&DataKey::Positions)
-            .unwrap_or(Map::new(&env));
-        legacy_positions.set(user, UserPosition { amount, side });
-        env.storage().persistent().set(&DataKey::Positions, &legacy_positions);
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if referral_bps > 10_000 {
+            return Err(ContractError::InvalidFee);
+        }
+
+        env.storage().persistent().set(&DataKey::ReferralBps, &referral_bps);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("referral"), symbol_short!("updated")),
+            referral_bps,
+        );
 
         Ok(())
     }
 
-    /// Places a precision prediction on the active round (Precision/Legends mode only)
-    /// predicted_price: price scaled to 4 decimals (e.g., 0.2297 → 2297)
-    pub fn place_precision_prediction(
-        env: Env,
-        user: Address,
-        amount: i128,
-        predicted_price: u128,
-    ) -> Result<(), ContractError> {
+    /// Returns the referrers' share of the protocol fee in basis points (0 if never set)
+    pub fn get_referral_bps(env: Env) -> u32 {
+        env.storage().persistent().get(&DataKey::ReferralBps).unwrap_or(0)
+    }
+
+    /// Records who referred `user`; allowed once, before the user's first bet
+    pub fn register_referral(env: Env, user: Address, referrer: Address) -> Result<(), ContractError> {
         user.require_auth();
 
-        if amount <= 0 {
-            return Err(ContractError::InvalidBetAmount);
+        if env.storage().persistent().has(&DataKey::Referrer(user.clone())) {
+            return Err(ContractError::AlreadyRegistered);
+        }
+        if Self::_has_bet(&env, &user) {
+            return Err(ContractError::RegistrationClosed);
         }
 
-        // Validate price scale (must be 4 decimal places, max value 9999 for 0.9999)
-        // Reasonable max: 99999999 (9999.9999 XLM)
-        if predicted_price > 99_999_999 {
-            return Err(ContractError::InvalidPriceScale);
+        // Walking up the referrer's chain must not lead back to the user
+        let mut ancestor = Some(referrer.clone());
+        while let Some(current) = ancestor {
+            if current == user {
+                return Err(ContractError::InvalidReferrer);
+            }
+            ancestor = Self::get_referrer(env.clone(), current);
         }
 
-        let round: Round = env.storage()
-            .persistent()
-            .get(&DataKey::ActiveRound)
-            .ok_or(ContractError::NoActiveRound)?;
+        env.storage().persistent().set(&DataKey::Referrer(user.clone()), &referrer);
 
-        // Verify round is in Precision mode
-        if round.mode != RoundMode::Precision {
-            return Err(ContractError::WrongModeForPrediction);
-        }
+        let mut stats = Self::get_referral_stats(env.clone(), referrer.clone());
+        stats.referred_users += 1;
+        env.storage().persistent().set(&DataKey::ReferralStats(referrer.clone()), &stats);
 
-        let current_ledger = env.ledger().sequence();
-        if current_ledger >= round.bet_end_ledger {
-            return Err(ContractError::RoundEnded);
-        }
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("referral"), symbol_short!("register")),
+            (user, referrer),
+        );
 
-        let user_balance = Self::balance(env.clone(), user.clone());
-        if user_balance < amount {
-            return Err(ContractError::InsufficientBalance);
+        Ok(())
+    }
+
+    /// Returns the address that referred `user`, if any
+    pub fn get_referrer(env: Env, user: Address) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Referrer(user))
+    }
+
+    /// Returns how many users a referrer brought in and the rewards credited to them
+    pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferralStats(referrer))
+            .unwrap_or(ReferralStats {
+                referred_users: 0,
+                total_rewards: 0,
+            })
+    }
+
+    /// True once the user has played a round or holds a stake in the active one
+    fn _has_bet(env: &Env, user: &Address) -> bool {
+        if Self::_load_stats(env, user).rounds_played > 0 {
+            return true;
         }
 
-        // Check if user already has a prediction in this round
-        let mut predictions: Vec<PrecisionPrediction> = env.storage()
+        let updown: Map<Address, UserPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::UpDownPositions)
+            .unwrap_or(Map::new(env));
+        let buckets: Map<Address, BucketPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::BucketPositions)
+            .unwrap_or(Map::new(env));
+        let precision: Vec<PrecisionPrediction> = env.storage()
             .persistent()
             .get(&DataKey::PrecisionPositions)
-            .unwrap_or(Vec::new(&env));
+            .unwrap_or(Vec::new(env));
 
-        for i in 0..predictions.len() {
-            if let Some(pred) = predictions.get(i) {
-                if pred.user == user {
-                    return Err(ContractError::AlreadyBet);
-                }
-            }
+        updown.contains_key(user.clone())
+            || buckets.contains_key(user.clone())
+            || Self::_precision_commits(env).contains_key(user.clone())
+            || precision.iter().any(|pred| pred.user == *user)
+    }
+
+    /// Credits a winner's referrer with their share of the fee attributed to the winner's stake
+    /// Rewards go to pending winnings outside any round, so they never lapse; returns the reward
+    fn _pay_referral(
+        env: &Env,
+        user: &Address,
+        fee: i128,
+        stake: i128,
+        winning_stake: i128,
+    ) -> Result<i128, ContractError> {
+        let referral_bps = Self::get_referral_bps(env.clone());
+        if fee <= 0 || referral_bps == 0 || winning_stake <= 0 {
+            return Ok(0);
         }
+        let referrer = match Self::get_referrer(env.clone(), user.clone()) {
+            Some(referrer) => referrer,
+            None => return Ok(0),
+        };
 
-        // Deduct balance
-        let new_balance = user_balance
-            .checked_sub(amount)
-            .ok_or(ContractError::Overflow)?;
-        Self::_set_balance(&env, user.clone(), new_balance);
+        let reward = fee
+            .checked_mul(stake)
+            .ok_or(ContractError::Overflow)?
+            / winning_stake
+            * referral_bps as i128
+            / 10_000;
+        if reward <= 0 {
+            return Ok(0);
+        }
 
-        // Store prediction
-        let prediction = PrecisionPrediction {
-            user: user.clone(),
-            predicted_price,
-            amount,
-        };
-        predictions.push_back(prediction);
+        Self::_adjust_pending(env, &referrer, reward)?;
 
-        env.storage().persistent().set(&DataKey::PrecisionPositions, &predictions);
+        let mut stats = Self::get_referral_stats(env.clone(), referrer.clone());
+        stats.total_rewards = stats.total_rewards
+            .checked_add(reward)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::ReferralStats(referrer.clone()), &stats);
 
-        // Emit event for precision prediction
         #[allow(deprecated)]
         env.events().publish(
-            (symbol_short!("predict"), symbol_short!("price")),
-            (user, predicted_price, round.start_ledger),
+            (symbol_short!("referral"), symbol_short!("reward")),
+            (referrer, user.clone(), reward),
         );
 
-        Ok(())
+        Ok(reward)
     }
 
-    /// Alias for place_precision_prediction - allows users to submit exact price predictions
-    /// guessed_price: price scaled to 4 decimals (e.g., 0.2297 → 2297)
-    pub fn predict_price(
-        env: Env,
-        user: Address,
-        guessed_price: u128,
-        amount: i128,
+    /// Sets the early-exit penalty for withdrawn bets (admin only)
+    pub fn set_exit_penalty(env: Env, penalty_bps: u32, destination: PenaltyDestination) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if penalty_bps > 10_000 {
+            return Err(ContractError::InvalidPenalty);
+        }
+
+        env.storage().persistent().set(&DataKey::ExitPenaltyBps, &penalty_bps);
+        env.storage().persistent().set(&DataKey::ExitPenaltyDestination, &destination);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("penalty"), symbol_short!("updated")),
+            (penalty_bps, destination),
+        );
+
+        Ok(())
+    }
+
+    /// Returns the early-exit penalty in basis points (0 if never set)
+    pub fn get_exit_penalty_bps(env: Env) -> u32 {
+        env.storage().persistent().get(&DataKey::ExitPenaltyBps).unwrap_or(0)
+    }
+
+    /// Returns where early-exit penalties go (the round's pool if never set)
+    pub fn get_exit_penalty_destination(env: Env) -> PenaltyDestination {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExitPenaltyDestination)
+            .unwrap_or(PenaltyDestination::Pool)
+    }
+
+    /// Sets stake limits for rounds of the given mode (admin only)
+    /// A limit of 0 disables that check
+    pub fn set_bet_limits(
+        env: Env,
+        mode: u32,
+        min_bet: i128,
+        max_bet_per_user: i128,
+        max_pool_size: i128,
     ) -> Result<(), ContractError> {
-        Self::place_precision_prediction(env, user, amount, guessed_price)
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        let round_mode = Self::_parse_mode(mode)?;
+
+        if min_bet < 0 || max_bet_per_user < 0 || max_pool_size < 0 {
+            return Err(ContractError::InvalidBetLimits);
+        }
+        if max_bet_per_user > 0 && min_bet > max_bet_per_user {
+            return Err(ContractError::InvalidBetLimits);
+        }
+
+        let limits = BetLimits {
+            min_bet,
+            max_bet_per_user,
+            max_pool_size,
+        };
+        env.storage().persistent().set(&DataKey::BetLimits(round_mode), &limits);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("limits"), symbol_short!("updated")),
+            (mode, min_bet, max_bet_per_user, max_pool_size),
+        );
+
+        Ok(())
     }
-    
-    /// Returns user's position in the current round (Up/Down mode)
-    pub fn get_user_position(env: Env, user: Address) -> Option<UserPosition> {
-        let positions: Map<Address, UserPosition> = env.storage()
+
+    /// Returns stake limits for the given mode (all 0 if never set)
+    pub fn get_bet_limits(env: Env, mode: u32) -> Result<BetLimits, ContractError> {
+        let round_mode = Self::_parse_mode(mode)?;
+        Ok(Self::_get_bet_limits(&env, &round_mode))
+    }
+
+    /// Returns all admin-configurable settings in one view
+    pub fn get_config(env: Env) -> ContractConfig {
+        ContractConfig {
+            bet_ledgers: env.storage().persistent().get(&DataKey::BetWindowLedgers).unwrap_or(6),
+            run_ledgers: env.storage().persistent().get(&DataKey::RunWindowLedgers).unwrap_or(12),
+            fee_bps: Self::get_fee_bps(env.clone()),
+            exit_penalty_bps: Self::get_exit_penalty_bps(env.clone()),
+            exit_penalty_destination: Self::get_exit_penalty_destination(env.clone()),
+            claim_window: Self::get_claim_window(env.clone()),
+            reveal_ledgers: Self::get_reveal_window(env.clone()),
+            updown_limits: Self::_get_bet_limits(&env, &RoundMode::UpDown),
+            precision_limits: Self::_get_bet_limits(&env, &RoundMode::Precision),
+            buckets_limits: Self::_get_bet_limits(&env, &RoundMode::Buckets),
+            bucket_bounds: Self::get_bucket_bounds(env.clone()),
+            precision_payout: Self::get_precision_payout(env.clone()),
+            precision_tie_rules: Self::get_precision_tie_rules(env.clone()),
+            referral_bps: Self::get_referral_bps(env.clone()),
+            faucet: Self::get_faucet_config(env.clone()),
+        }
+    }
+
+    /// Maps a numeric mode to a RoundMode: 0 = Up/Down, 1 = Precision, 2 = Buckets
+    fn _parse_mode(mode: u32) -> Result<RoundMode, ContractError> {
+        match mode {
+            0 => Ok(RoundMode::UpDown),
+            1 => Ok(RoundMode::Precision),
+            2 => Ok(RoundMode::Buckets),
+            _ => Err(ContractError::InvalidMode),
+        }
+    }
+
+    fn _get_bet_limits(env: &Env, mode: &RoundMode) -> BetLimits {
+        env.storage()
             .persistent()
-            .get(&DataKey::UpDownPositions)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::BetLimits(mode.clone()))
+            .unwrap_or(BetLimits {
+                min_bet: 0,
+                max_bet_per_user: 0,
+                max_pool_size: 0,
+            })
+    }
 
-        positions.get(user)
+    /// Checks a user's resulting stake and the round's resulting pool against the mode's limits
+    fn _check_bet_limits(env: &Env, mode: &RoundMode, stake: i128, pool: i128) -> Result<(), ContractError> {
+        let limits = Self::_get_bet_limits(env, mode);
+
+        if stake < limits.min_bet {
+            return Err(ContractError::BetBelowMinimum);
+        }
+        if limits.max_bet_per_user > 0 && stake > limits.max_bet_per_user {
+            return Err(ContractError::BetAboveMaximum);
+        }
+        if limits.max_pool_size > 0 && pool > limits.max_pool_size {
+            return Err(ContractError::PoolCapExceeded);
+        }
+
+        Ok(())
     }
 
-    /// Returns user's precision prediction in the current round (Precision mode)
-    pub fn get_user_precision_prediction(env: Env, user: Address) -> Option<PrecisionPrediction> {
-        let predictions: Vec<PrecisionPrediction> = env.storage()
+    /// Sets the bucket boundaries used by new Buckets rounds (admin only)
+    /// Bounds are basis points of change from price_start, strictly ascending;
+    /// n bounds make n + 1 buckets and a bucket includes its lower bound
+    pub fn set_bucket_bounds(env: Env, bounds: Vec<i32>) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
             .persistent()
-            .get(&DataKey::PrecisionPositions)
-            .unwrap_or(Vec::new(&env));
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
 
-        for i in 0..predictions.len() {
-            if let Some(pred) = predictions.get(i) {
-                if pred.user == user {
-                    return Some(pred);
+        admin.require_auth();
+
+        if bounds.is_empty() || bounds.len() > MAX_BUCKET_BOUNDS {
+            return Err(ContractError::InvalidBuckets);
+        }
+        for i in 1..bounds.len() {
+            if let (Some(prev), Some(bound)) = (bounds.get(i - 1), bounds.get(i)) {
+                if bound <= prev {
+                    return Err(ContractError::InvalidBuckets);
                 }
             }
         }
-        None
+
+        env.storage().persistent().set(&DataKey::BucketBounds, &bounds);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("buckets"), symbol_short!("updated")),
+            bounds,
+        );
+
+        Ok(())
     }
 
-    /// Returns all precision predictions for the current round
-    pub fn get_precision_predictions(env: Env) -> Vec<PrecisionPrediction> {
+    /// Returns the bucket boundaries for new Buckets rounds
+    /// Defaults to <-2%, -2..-0.5%, flat, +0.5..+2%, >=+2%
+    pub fn get_bucket_bounds(env: Env) -> Vec<i32> {
         env.storage()
             .persistent()
-            .get(&DataKey::PrecisionPositions)
+            .get(&DataKey::BucketBounds)
+            .unwrap_or(vec![&env, -200, -50, 50, 200])
+    }
+
+    /// Sets how new Precision rounds share the pot among the closest guesses (admin only)
+    pub fn set_precision_payout(env: Env, payout: PrecisionPayout) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        match &payout {
+            PrecisionPayout::Even => {},
+            PrecisionPayout::StakeWeighted(weighting) => {
+                if weighting.max_win_bps == 0 {
+                    return Err(ContractError::InvalidPayoutPolicy);
+                }
+            },
+            PrecisionPayout::Tiered(tiers) => {
+                // Every tier pays something and the table covers the whole pot
+                if tiers.is_empty() || tiers.len() > MAX_PAYOUT_TIERS {
+                    return Err(ContractError::InvalidPayoutPolicy);
+                }
+                let mut total: u32 = 0;
+                for tier in tiers.iter() {
+                    if tier == 0 {
+                        return Err(ContractError::InvalidPayoutPolicy);
+                    }
+                    total = total.saturating_add(tier);
+                }
+                if total != 10_000 {
+                    return Err(ContractError::InvalidPayoutPolicy);
+                }
+            },
+            PrecisionPayout::Scored(scoring) => {
+                if scoring.width == 0 || scoring.width > MAX_SCORING_WIDTH {
+                    return Err(ContractError::InvalidPayoutPolicy);
+                }
+            },
+        }
+
+        env.storage().persistent().set(&DataKey::PrecisionPayout, &payout);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("payout"), symbol_short!("updated")),
+            payout,
+        );
+
+        Ok(())
+    }
+
+    /// Returns the payout policy for new Precision rounds (Even if never set)
+    pub fn get_precision_payout(env: Env) -> PrecisionPayout {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PrecisionPayout)
+            .unwrap_or(PrecisionPayout::Even)
+    }
+
+    /// Sets the tie band and exact-hit bonus for new Precision rounds (admin only)
+    pub fn set_precision_tie_rules(env: Env, rules: PrecisionTieRules) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        let valid_tolerance = match rules.tolerance {
+            Tolerance::Exact => true,
+            Tolerance::Units(units) => units <= MAX_TOLERANCE_UNITS,
+            Tolerance::Bps(bps) => bps <= 10_000,
+        };
+        if !valid_tolerance || rules.bonus_pool_bps > 10_000 {
+            return Err(ContractError::InvalidPayoutPolicy);
+        }
+
+        env.storage().persistent().set(&DataKey::PrecisionTieRules, &rules);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("ties"), symbol_short!("updated")),
+            rules,
+        );
+
+        Ok(())
+    }
+
+    /// Returns the tie rules for new Precision rounds (exact ties, no bonus if never set)
+    pub fn get_precision_tie_rules(env: Env) -> PrecisionTieRules {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PrecisionTieRules)
+            .unwrap_or(PrecisionTieRules {
+                tolerance: Tolerance::Exact,
+                bonus_multiplier_bps: 0,
+                bonus_pool_bps: 0,
+            })
+    }
+
+    /// Returns user statistics (wins, losses, streaks, volume, P&L and per-mode breakdown)
+    pub fn get_user_stats(env: Env, user: Address) -> UserStats {
+        Self::_load_stats(&env, &user)
+    }
+
+    /// Returns up to `limit` entries of a leaderboard (at most LEADERBOARD_SIZE), best first
+    pub fn get_leaderboard(env: Env, kind: LeaderboardKind, limit: u32) -> Vec<LeaderboardEntry> {
+        Self::_leaderboard(&env, None, kind, limit)
+    }
+
+    /// Starts a season with fresh stats and leaderboards (admin only)
+    /// At season end the prize pool is paid to the top of the `ranking` leaderboard by `prize_tiers` bps
+    pub fn start_season(
+        env: Env,
+        prize_pool: i128,
+        prize_tiers: Vec<u32>,
+        ranking: LeaderboardKind,
+    ) -> Result<u32, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if env.storage().persistent().has(&DataKey::Season(SeasonKey::Active)) {
+            return Err(ContractError::SeasonActive);
+        }
+
+        if prize_pool < 0 {
+            return Err(ContractError::InvalidPrizes);
+        }
+        Self::_check_prize_tiers(&prize_tiers)?;
+
+        let season_id = env.storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::Season(SeasonKey::Counter))
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+
+        let season = Season {
+            season_id,
+            start_ledger: env.ledger().sequence(),
+            end_ledger: None,
+            prize_pool,
+            prize_tiers,
+            ranking,
+            prizes_paid: 0,
+        };
+
+        env.storage().persistent().set(&DataKey::Season(SeasonKey::Info(season_id)), &season);
+        env.storage().persistent().set(&DataKey::Season(SeasonKey::Counter), &season_id);
+        env.storage().persistent().set(&DataKey::Season(SeasonKey::Active), &season_id);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("season"), symbol_short!("started")),
+            (season_id, prize_pool),
+        );
+
+        Ok(season_id)
+    }
+
+    /// Ends the running season and pays its prizes straight to winners' balances (admin only)
+    /// Returns the total prize paid
+    pub fn end_season(env: Env) -> Result<i128, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        let season_id: u32 = env.storage()
+            .persistent()
+            .get(&DataKey::Season(SeasonKey::Active))
+            .ok_or(ContractError::NoActiveSeason)?;
+        let mut season: Season = env.storage()
+            .persistent()
+            .get(&DataKey::Season(SeasonKey::Info(season_id)))
+            .ok_or(ContractError::NoActiveSeason)?;
+
+        // The running board only re-ranks users as they play, so prizes follow a ranking rebuilt
+        // from every participant's final season stats, which also replaces the stored board
+        let board = Self::_rank_season(&env, season_id, &season.ranking);
+        env.storage().persistent().set(&Self::_leaderboard_key(Some(season_id), season.ranking.clone()), &board);
+        for (position, entry) in board.iter().take(season.prize_tiers.len() as usize).enumerate() {
+            let tier = season.prize_tiers.get(position as u32).unwrap_or(0);
+            let prize = season.prize_pool
+                .checked_mul(tier as i128)
+                .ok_or(ContractError::Overflow)?
+                / 10_000;
+            if prize == 0 {
+                continue;
+            }
+
+            let balance = Self::balance(env.clone(), entry.user.clone());
+            let new_balance = balance
+                .checked_add(prize)
+                .ok_or(ContractError::Overflow)?;
+            Self::_set_balance(&env, entry.user.clone(), new_balance)?;
+            season.prizes_paid = season.prizes_paid
+                .checked_add(prize)
+                .ok_or(ContractError::Overflow)?;
+
+            #[allow(deprecated)]
+            env.events().publish(
+                (symbol_short!("season"), symbol_short!("prize")),
+                (entry.user, season_id, prize),
+            );
+        }
+
+        // Prizes are new vXLM
+        Self::_adjust_supply(&env, TokenKey::TotalSupply, season.prizes_paid)?;
+
+        season.end_ledger = Some(env.ledger().sequence());
+        env.storage().persistent().set(&DataKey::Season(SeasonKey::Info(season_id)), &season);
+        env.storage().persistent().remove(&DataKey::Season(SeasonKey::Active));
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("season"), symbol_short!("ended")),
+            (season_id, season.prizes_paid),
+        );
+
+        Ok(season.prizes_paid)
+    }
+
+    /// Ranks every season participant by their stored season stats, best first
+    fn _rank_season(env: &Env, season_id: u32, kind: &LeaderboardKind) -> Vec<LeaderboardEntry> {
+        let participants: Vec<Address> = env.storage()
+            .persistent()
+            .get(&DataKey::Season(SeasonKey::Participants(season_id)))
+            .unwrap_or(Vec::new(env));
+
+        let mut board = Vec::new(env);
+        for user in participants.iter() {
+            let stats = Self::get_season_stats(env.clone(), season_id, user.clone());
+            if let Some(score) = Self::_leaderboard_score(&stats, kind) {
+                Self::_insert_ranked(&mut board, kind, LeaderboardEntry { user, score });
+            }
+        }
+        board
+    }
+
+    /// Returns a season's settings and, once ended, its result
+    pub fn get_season(env: Env, season_id: u32) -> Option<Season> {
+        env.storage().persistent().get(&DataKey::Season(SeasonKey::Info(season_id)))
+    }
+
+    /// Returns the running season, if any
+    pub fn get_active_season(env: Env) -> Option<Season> {
+        let season_id: u32 = env.storage().persistent().get(&DataKey::Season(SeasonKey::Active))?;
+        Self::get_season(env, season_id)
+    }
+
+    /// Returns a user's stats for a single season
+    pub fn get_season_stats(env: Env, season_id: u32, user: Address) -> UserStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Season(SeasonKey::Stats(season_id, user)))
+            .unwrap_or(Self::_empty_stats())
+    }
+
+    /// Returns the badges a user has earned, in the order they were earned
+    /// Badges are tied to the address and cannot be transferred
+    pub fn get_achievements(env: Env, user: Address) -> Vec<Achievement> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Achievements(user))
             .unwrap_or(Vec::new(&env))
     }
 
-    /// Returns all Up/Down positions for the current round
-    pub fn get_updown_positions(env: Env) -> Map<Address, UserPosition> {
+    /// Returns up to `limit` entries of a season's leaderboard, best first
+    pub fn get_season_leaderboard(
+        env: Env,
+        season_id: u32,
+        kind: LeaderboardKind,
+        limit: u32,
+    ) -> Vec<LeaderboardEntry> {
+        Self::_leaderboard(&env, Some(season_id), kind, limit)
+    }
+
+    fn _leaderboard(env: &Env, season_id: Option<u32>, kind: LeaderboardKind, limit: u32) -> Vec<LeaderboardEntry> {
+        let board: Vec<LeaderboardEntry> = env.storage()
+            .persistent()
+            .get(&Self::_leaderboard_key(season_id, kind))
+            .unwrap_or(Vec::new(env));
+
+        let limit = limit.min(LEADERBOARD_SIZE);
+        if board.len() <= limit {
+            board
+        } else {
+            board.slice(0..limit)
+        }
+    }
+    
+    /// Prize tiers must be 1 to LEADERBOARD_SIZE positive bps shares summing to 10000
+    fn _check_prize_tiers(prize_tiers: &Vec<u32>) -> Result<(), ContractError> {
+        if prize_tiers.is_empty() || prize_tiers.len() > LEADERBOARD_SIZE {
+            return Err(ContractError::InvalidPrizes);
+        }
+        let mut total: u32 = 0;
+        for tier in prize_tiers.iter() {
+            if tier == 0 {
+                return Err(ContractError::InvalidPrizes);
+            }
+            total = total.saturating_add(tier);
+        }
+        if total != 10_000 {
+            return Err(ContractError::InvalidPrizes);
+        }
+
+        Ok(())
+    }
+
+    /// Creates a tournament open for registration (admin only)
+    /// Players pay `entry_fee` for `starting_chips`; after `total_rounds` rounds the entry fees
+    /// are paid to the chip leaders by `prize_tiers` bps
+    pub fn create_tournament(
+        env: Env,
+        entry_fee: i128,
+        starting_chips: i128,
+        total_rounds: u32,
+        prize_tiers: Vec<u32>,
+    ) -> Result<u32, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if entry_fee < 0 || starting_chips <= 0 || total_rounds == 0 {
+            return Err(ContractError::InvalidParameters);
+        }
+        Self::_check_prize_tiers(&prize_tiers)?;
+
+        let tournament_id = env.storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::TournamentCounter)
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+
+        let tournament = Tournament {
+            tournament_id,
+            entry_fee,
+            starting_chips,
+            total_rounds,
+            rounds_played: 0,
+            prize_tiers,
+            prize_pool: 0,
+            jackpot: 0,
+            players: Vec::new(&env),
+            status: TournamentStatus::Registration,
+        };
+
+        env.storage().persistent().set(&DataKey::Tournament(tournament_id), &tournament);
+        env.storage().persistent().set(&DataKey::TournamentCounter, &tournament_id);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("tourney"), symbol_short!("created")),
+            (tournament_id, entry_fee, total_rounds),
+        );
+
+        Ok(tournament_id)
+    }
+
+    /// Pays the entry fee from the user's balance and hands out their starting chips
+    pub fn join_tournament(env: Env, user: Address, tournament_id: u32) -> Result<(), ContractError> {
+        user.require_auth();
+
+        let mut tournament = Self::_tournament(&env, tournament_id)?;
+
+        if tournament.status != TournamentStatus::Registration {
+            return Err(ContractError::RegistrationClosed);
+        }
+        if tournament.players.contains(&user) {
+            return Err(ContractError::AlreadyRegistered);
+        }
+        if tournament.players.len() >= MAX_TOURNAMENT_PLAYERS {
+            return Err(ContractError::RegistrationClosed);
+        }
+
+        let user_balance = Self::balance(env.clone(), user.clone());
+        if user_balance < tournament.entry_fee {
+            return Err(ContractError::InsufficientBalance);
+        }
+        let new_balance = user_balance
+            .checked_sub(tournament.entry_fee)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(&env, user.clone(), new_balance)?;
+
+        tournament.prize_pool = tournament.prize_pool
+            .checked_add(tournament.entry_fee)
+            .ok_or(ContractError::Overflow)?;
+        Self::_adjust_supply(&env, TokenKey::TournamentPools, tournament.entry_fee)?;
+        tournament.players.push_back(user.clone());
+        env.storage().persistent().set(&DataKey::Tournament(tournament_id), &tournament);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TournamentChips(tournament_id, user.clone()), &tournament.starting_chips);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("tourney"), symbol_short!("joined")),
+            (user, tournament_id),
+        );
+
+        Ok(())
+    }
+
+    /// Creates the tournament's next round (admin only); the first one closes registration
+    /// Stakes and payouts in the round use tournament chips; fees and the shared jackpot are not involved
+    pub fn create_tournament_round(
+        env: Env,
+        tournament_id: u32,
+        start_price: u128,
+        mode: Option<u32>,
+    ) -> Result<(), ContractError> {
+        if start_price == 0 {
+            return Err(ContractError::InvalidPrice);
+        }
+
+        let mode_value = mode.unwrap_or(0);
+        let round_mode = Self::_parse_mode(mode_value)?;
+
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        let mut tournament = Self::_tournament(&env, tournament_id)?;
+        if tournament.status == TournamentStatus::Finished || tournament.rounds_played >= tournament.total_rounds {
+            return Err(ContractError::TournamentOver);
+        }
+        if tournament.players.is_empty() {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        // Stakes in an unresolved regular round would be lost if the tournament round replaced it
+        if let Some(round) = env.storage().persistent().get::<_, Round>(&DataKey::ActiveRound) {
+            if Self::_round_stake(&env, &round)? > 0 {
+                return Err(ContractError::RoundNotEnded);
+            }
+        }
+
+        tournament.status = TournamentStatus::Running;
+        env.storage().persistent().set(&DataKey::Tournament(tournament_id), &tournament);
+
+        Self::_open_round(&env, start_price, round_mode, mode_value, Some(tournament_id))
+    }
+
+    /// Ends the tournament and pays the prize pool to the chip leaders' balances (admin only)
+    /// Allowed once every round is resolved, or earlier when at most one player has chips left.
+    /// Eliminated players win nothing; tiers beyond the surviving players are shared out among
+    /// those who placed, and players tied on chips split the tiers they span.
+    /// If everyone is eliminated the entry fees are refunded evenly.
+    pub fn finish_tournament(env: Env, tournament_id: u32) -> Result<Vec<TournamentStanding>, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        let mut tournament = Self::_tournament(&env, tournament_id)?;
+        if tournament.status == TournamentStatus::Finished {
+            return Err(ContractError::TournamentOver);
+        }
+
+        let mut standings = Self::_standings(&env, &tournament);
+        let survivors = standings.iter().filter(|standing| !standing.eliminated).count() as u32;
+        let round_open = env.storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::ActiveTournamentRound)
+            == Some(tournament_id);
+        if round_open || (tournament.rounds_played < tournament.total_rounds && survivors > 1) {
+            return Err(ContractError::TournamentNotOver);
+        }
+
+        let tier = |position: u32| tournament.prize_tiers.get(position).unwrap_or(0) as i128;
+        let mut placed_bps: i128 = 0;
+        for position in 0..survivors {
+            placed_bps += tier(position);
+        }
+
+        let mut paid: i128 = 0;
+        let mut start = 0;
+        while start < standings.len() {
+            let rank = standings.get(start).map(|standing| standing.rank).unwrap_or(0);
+            let mut end = start + 1;
+            while end < standings.len() && standings.get(end).map(|standing| standing.rank) == Some(rank) {
+                end += 1;
+            }
+
+            let prize = if survivors == 0 {
+                tournament.prize_pool / standings.len() as i128
+            } else if start < survivors {
+                let mut group_bps: i128 = 0;
+                for position in start..end {
+                    group_bps += tier(position);
+                }
+                tournament.prize_pool
+                    .checked_mul(group_bps)
+                    .ok_or(ContractError::Overflow)?
+                    / placed_bps
+                    / (end - start) as i128
+            } else {
+                0
+            };
+
+            for position in start..end {
+                let mut standing = match standings.get(position) {
+                    Some(standing) => standing,
+                    None => continue,
+                };
+                if prize > 0 {
+                    let new_balance = Self::balance(env.clone(), standing.user.clone())
+                        .checked_add(prize)
+                        .ok_or(ContractError::Overflow)?;
+                    Self::_set_balance(&env, standing.user.clone(), new_balance)?;
+                    paid = paid
+                        .checked_add(prize)
+                        .ok_or(ContractError::Overflow)?;
+
+                    #[allow(deprecated)]
+                    env.events().publish(
+                        (symbol_short!("tourney"), symbol_short!("prize")),
+                        (standing.user.clone(), tournament_id, prize),
+                    );
+                }
+                standing.prize = prize;
+                standings.set(position, standing);
+            }
+
+            start = end;
+        }
+
+        // Whatever the prize split rounds away is left behind as dust
+        Self::_adjust_supply(&env, TokenKey::TournamentPools, -tournament.prize_pool)?;
+        Self::_adjust_supply(&env, TokenKey::Dust, tournament.prize_pool - paid)?;
+
+        tournament.status = TournamentStatus::Finished;
+        env.storage().persistent().set(&DataKey::Tournament(tournament_id), &tournament);
+        env.storage().persistent().set(&DataKey::TournamentStandings(tournament_id), &standings);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("tourney"), symbol_short!("finished")),
+            (tournament_id, paid),
+        );
+
+        Ok(standings)
+    }
+
+    /// Returns a tournament's settings and progress
+    pub fn get_tournament(env: Env, tournament_id: u32) -> Option<Tournament> {
+        env.storage().persistent().get(&DataKey::Tournament(tournament_id))
+    }
+
+    /// Returns a player's chips in a tournament, excluding any staked in the active round
+    pub fn get_tournament_chips(env: Env, tournament_id: u32, user: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TournamentChips(tournament_id, user))
+            .unwrap_or(0)
+    }
+
+    /// Returns the tournament's players ranked by chips, with prizes once it has finished
+    pub fn get_tournament_standings(env: Env, tournament_id: u32) -> Result<Vec<TournamentStanding>, ContractError> {
+        if let Some(standings) = env.storage().persistent().get(&DataKey::TournamentStandings(tournament_id)) {
+            return Ok(standings);
+        }
+        let tournament = Self::_tournament(&env, tournament_id)?;
+        Ok(Self::_standings(&env, &tournament))
+    }
+
+    fn _tournament(env: &Env, tournament_id: u32) -> Result<Tournament, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Tournament(tournament_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Ranks players by chips, most first; ties keep joining order and share a rank
+    fn _standings(env: &Env, tournament: &Tournament) -> Vec<TournamentStanding> {
+        let mut standings: Vec<TournamentStanding> = Vec::new(env);
+        for user in tournament.players.iter() {
+            let chips = Self::get_tournament_chips(env.clone(), tournament.tournament_id, user.clone());
+            let standing = TournamentStanding {
+                user,
+                chips,
+                rank: 0,
+                eliminated: chips == 0,
+                prize: 0,
+            };
+
+            let mut index = standings.len();
+            while index > 0 && standings.get(index - 1).map(|above| above.chips < chips).unwrap_or(false) {
+                index -= 1;
+            }
+            standings.insert(index, standing);
+        }
+
+        let mut previous: Option<i128> = None;
+        for position in 0..standings.len() {
+            if let Some(mut standing) = standings.get(position) {
+                standing.rank = match previous {
+                    Some(chips) if chips == standing.chips => {
+                        standings.get(position - 1).map(|above| above.rank).unwrap_or(1)
+                    },
+                    _ => position + 1,
+                };
+                previous = Some(standing.chips);
+                standings.set(position, standing);
+            }
+        }
+
+        standings
+    }
+
+    /// Balance a stake in `round` is drawn from: the player's tournament chips, or vXLM
+    fn _stake_balance(env: &Env, round: &Round, user: &Address) -> i128 {
+        match round.tournament_id {
+            Some(tournament_id) => Self::get_tournament_chips(env.clone(), tournament_id, user.clone()),
+            None => Self::balance(env.clone(), user.clone()),
+        }
+    }
+
+    fn _set_stake_balance(env: &Env, round: &Round, user: &Address, amount: i128) -> Result<(), ContractError> {
+        match round.tournament_id {
+            Some(tournament_id) => {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::TournamentChips(tournament_id, user.clone()), &amount);
+                Ok(())
+            },
+            None => Self::_set_balance(env, user.clone(), amount),
+        }
+    }
+
+    /// Returns user's claimable winnings
+    pub fn get_pending_winnings(env: Env, user: Address) -> i128 {
+        let key = DataKey::PendingWinnings(user);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Returns user's unclaimed winnings broken down by round, oldest first
+    pub fn get_claimable_rounds(env: Env, user: Address) -> Vec<RoundClaim> {
+        let round_ids: Vec<u32> = env.storage()
+            .persistent()
+            .get(&DataKey::ClaimableRounds(user.clone()))
+            .unwrap_or(Vec::new(&env));
+
+        let mut claims = Vec::new(&env);
+        for round_id in round_ids.iter() {
+            let amount: i128 = env.storage()
+                .persistent()
+                .get(&DataKey::RoundWinnings(user.clone(), round_id))
+                .unwrap_or(0);
+            claims.push_back(RoundClaim { round_id, amount });
+        }
+        claims
+    }
+    
+    /// Places a bet on the active round (Up/Down mode only)
+    pub fn place_bet(env: Env, user: Address, amount: i128, side: BetSide) -> Result<(), ContractError> {
+        user.require_auth();
+
+        Self::_place_bet(&env, &user, amount, side)
+    }
+
+    /// Places an Up/Down bet once the caller has authorized it for `user`
+    fn _place_bet(env: &Env, user: &Address, amount: i128, side: BetSide) -> Result<(), ContractError> {
+        if amount <= 0 {
+            return Err(ContractError::InvalidBetAmount);
+        }
+
+        let mut round: Round = env.storage()
+            .persistent()
+            .get(&DataKey::ActiveRound)
+            .ok_or(ContractError::NoActiveRound)?;
+
+        // Verify round is in Up/Down mode
+        if round.mode != RoundMode::UpDown {
+            return Err(ContractError::WrongModeForPrediction);
+        }
+
+        let current_ledger = env.ledger().sequence();
+        if current_ledger >= round.bet_end_ledger {
+            return Err(ContractError::RoundEnded);
+        }
+
+        let user_balance = Self::_stake_balance(env, &round, user);
+        if user_balance < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        // Use UpDownPositions storage for Up/Down mode
+        let mut positions: Map<Address, UserPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::UpDownPositions)
+            .unwrap_or(Map::new(env));
+
+        if positions.contains_key(user.clone()) {
+            return Err(ContractError::AlreadyBet);
+        }
+
+        let pool = round.pool_up
+            .checked_add(round.pool_down)
+            .and_then(|pool| pool.checked_add(amount))
+            .ok_or(ContractError::Overflow)?;
+        Self::_check_bet_limits(env, &round.mode, amount, pool)?;
+
+        let new_balance = user_balance
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_stake_balance(env, &round, user, new_balance)?;
+
+        let position = UserPosition {
+            amount,
+            side: side.clone(),
+        };
+        positions.set(user.clone(), position);
+
+        match side {
+            BetSide::Up => {
+                round.pool_up = round.pool_up
+                    .checked_add(amount)
+                    .ok_or(ContractError::Overflow)?;
+            },
+            BetSide::Down => {
+                round.pool_down = round.pool_down
+                    .checked_add(amount)
+                    .ok_or(ContractError::Overflow)?;
+            },
+        }
+
+        env.storage().persistent().set(&DataKey::UpDownPositions, &positions);
+        env.storage().persistent().set(&DataKey::ActiveRound, &round);
+
+        // Also keep legacy Positions storage for backwards compatibility
+        let mut legacy_positions: Map<Address, UserPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::Positions)
+            .unwrap_or(Map::new(env));
+        legacy_positions.set(user.clone(), UserPosition { amount, side });
+        env.storage().persistent().set(&DataKey::Positions, &legacy_positions);
+
+        Ok(())
+    }
+
+    /// Adds to an existing Up/Down bet on the active round while betting is open
+    pub fn increase_bet(env: Env, user: Address, round_id: u32, amount: i128) -> Result<(), ContractError> {
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidBetAmount);
+        }
+
+        let (mut round, mut positions, mut position) = Self::_open_position(&env, &user, round_id)?;
+
+        let user_balance = Self::_stake_balance(&env, &round, &user);
+        if user_balance < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        let stake = position.amount
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        let pool = round.pool_up
+            .checked_add(round.pool_down)
+            .and_then(|pool| pool.checked_add(amount))
+            .ok_or(ContractError::Overflow)?;
+        Self::_check_bet_limits(&env, &round.mode, stake, pool)?;
+
+        let new_balance = user_balance
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_stake_balance(&env, &round, &user, new_balance)?;
+
+        position.amount = position.amount
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+
+        match position.side {
+            BetSide::Up => {
+                round.pool_up = round.pool_up
+                    .checked_add(amount)
+                    .ok_or(ContractError::Overflow)?;
+            },
+            BetSide::Down => {
+                round.pool_down = round.pool_down
+                    .checked_add(amount)
+                    .ok_or(ContractError::Overflow)?;
+            },
+        }
+
+        positions.set(user.clone(), position.clone());
+        Self::_store_positions(&env, &round, &positions, &user, Some(position));
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("bet"), symbol_short!("increased")),
+            (user, round_id, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Withdraws part or all of an Up/Down bet while betting is open
+    /// The configured early-exit penalty is kept back; returns the amount refunded
+    pub fn withdraw_bet(env: Env, user: Address, round_id: u32, amount: i128) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidBetAmount);
+        }
+
+        let (mut round, mut positions, mut position) = Self::_open_position(&env, &user, round_id)?;
+
+        if amount > position.amount {
+            return Err(ContractError::InsufficientStake);
+        }
+
+        // A partial withdrawal may not leave less than the minimum bet behind
+        let remaining = position.amount - amount;
+        let min_bet = Self::_get_bet_limits(&env, &round.mode).min_bet;
+        if remaining > 0 && remaining < min_bet {
+            return Err(ContractError::BetBelowMinimum);
+        }
+
+        let penalty_bps: u32 = env.storage()
+            .persistent()
+            .get(&DataKey::ExitPenaltyBps)
+            .unwrap_or(0);
+        let penalty = amount
+            .checked_mul(penalty_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / 10_000;
+        let refund = amount
+            .checked_sub(penalty)
+            .ok_or(ContractError::Overflow)?;
+
+        match position.side {
+            BetSide::Up => {
+                round.pool_up = round.pool_up
+                    .checked_sub(amount)
+                    .ok_or(ContractError::Overflow)?;
+            },
+            BetSide::Down => {
+                round.pool_down = round.pool_down
+                    .checked_sub(amount)
+                    .ok_or(ContractError::Overflow)?;
+            },
+        }
+
+        if penalty > 0 {
+            // Tournament chips never reach the treasury, so their penalties always stay in the pot
+            let destination = match round.tournament_id {
+                Some(_) => PenaltyDestination::Pool,
+                None => Self::get_exit_penalty_destination(env.clone()),
+            };
+            match destination {
+                PenaltyDestination::Pool => {
+                    round.jackpot = round.jackpot
+                        .checked_add(penalty)
+                        .ok_or(ContractError::Overflow)?;
+                },
+                PenaltyDestination::Treasury => Self::_credit_treasury(&env, penalty)?,
+            }
+        }
+
+        position.amount = position.amount
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        let remaining = if position.amount == 0 {
+            positions.remove(user.clone());
+            None
+        } else {
+            positions.set(user.clone(), position.clone());
+            Some(position)
+        };
+        Self::_store_positions(&env, &round, &positions, &user, remaining);
+
+        let user_balance = Self::_stake_balance(&env, &round, &user);
+        let new_balance = user_balance
+            .checked_add(refund)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_stake_balance(&env, &round, &user, new_balance)?;
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("bet"), symbol_short!("withdrawn")),
+            (user, round_id, refund, penalty),
+        );
+
+        Ok(refund)
+    }
+
+    /// Loads the active Up/Down round and the user's open position, checking the bet window
+    fn _open_position(
+        env: &Env,
+        user: &Address,
+        round_id: u32,
+    ) -> Result<(Round, Map<Address, UserPosition>, UserPosition), ContractError> {
+        let round = Self::_updown_round(env, round_id)?;
+
+        if env.ledger().sequence() >= round.bet_end_ledger {
+            return Err(ContractError::RoundEnded);
+        }
+
+        let positions: Map<Address, UserPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::UpDownPositions)
+            .unwrap_or(Map::new(env));
+
+        let position = positions.get(user.clone()).ok_or(ContractError::NoPosition)?;
+
+        Ok((round, positions, position))
+    }
+
+    /// Persists an updated round and positions, mirroring the change into legacy Positions
+    fn _store_positions(
+        env: &Env,
+        round: &Round,
+        positions: &Map<Address, UserPosition>,
+        user: &Address,
+        position: Option<UserPosition>,
+    ) {
+        env.storage().persistent().set(&DataKey::UpDownPositions, positions);
+        env.storage().persistent().set(&DataKey::ActiveRound, round);
+
+        let mut legacy_positions: Map<Address, UserPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::Positions)
+            .unwrap_or(Map::new(env));
+        match position {
+            Some(position) => legacy_positions.set(user.clone(), position),
+            None => {
+                legacy_positions.remove(user.clone());
+            },
+        }
+        env.storage().persistent().set(&DataKey::Positions, &legacy_positions);
+    }
+
+    /// Quotes the payout for a new Up/Down stake if `side` wins, given the current pools
+    /// Uses the same integer math as resolution, including the protocol fee and jackpot;
+    /// while the other side is empty the round would be voided, so the quote is a 1x refund
+    pub fn quote_bet(env: Env, round_id: u32, side: BetSide, amount: i128) -> Result<BetQuote, ContractError> {
+        if amount <= 0 {
+            return Err(ContractError::InvalidBetAmount);
+        }
+
+        let round = Self::_updown_round(&env, round_id)?;
+        let (winning_pool, losing_pool) = match side {
+            BetSide::Up => (round.pool_up, round.pool_down),
+            BetSide::Down => (round.pool_down, round.pool_up),
+        };
+        let winning_pool = winning_pool
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+
+        let payout = if losing_pool == 0 {
+            amount
+        } else {
+            let distributable = Self::_distributable_pool(&env, &round, losing_pool)?;
+            Self::_winner_payout(amount, winning_pool, distributable)?
+        };
+
+        Ok(BetQuote {
+            payout,
+            net_winnings: payout - amount,
+            multiplier_bps: Self::_multiplier_bps(payout, amount)?,
+        })
+    }
+
+    /// Returns the current pools and the multiplier each side would be paid if it won now
+    /// A side with no bets is quoted 0; a side facing an empty pool would be refunded at 1x
+    pub fn get_round_odds(env: Env, round_id: u32) -> Result<RoundOdds, ContractError> {
+        let round = Self::_updown_round(&env, round_id)?;
+
+        let up_multiplier_bps = Self::_side_multiplier_bps(&env, &round, round.pool_up, round.pool_down)?;
+        let down_multiplier_bps = Self::_side_multiplier_bps(&env, &round, round.pool_down, round.pool_up)?;
+
+        Ok(RoundOdds {
+            round_id,
+            pool_up: round.pool_up,
+            pool_down: round.pool_down,
+            up_multiplier_bps,
+            down_multiplier_bps,
+        })
+    }
+
+    fn _side_multiplier_bps(env: &Env, round: &Round, pool: i128, opposing_pool: i128) -> Result<i128, ContractError> {
+        if pool == 0 {
+            return Ok(0);
+        }
+        if opposing_pool == 0 {
+            return Ok(10_000);
+        }
+
+        let distributable = Self::_distributable_pool(env, round, opposing_pool)?;
+        let payout = Self::_winner_payout(pool, pool, distributable)?;
+        Self::_multiplier_bps(payout, pool)
+    }
+
+    /// Loads the active round, checking it matches `round_id` and is an Up/Down round
+    fn _updown_round(env: &Env, round_id: u32) -> Result<Round, ContractError> {
+        let round: Round = env.storage()
+            .persistent()
+            .get(&DataKey::ActiveRound)
+            .ok_or(ContractError::NoActiveRound)?;
+
+        if round.round_id != round_id {
+            return Err(ContractError::RoundMismatch);
+        }
+
+        if round.mode != RoundMode::UpDown {
+            return Err(ContractError::WrongModeForPrediction);
+        }
+
+        Ok(round)
+    }
+
+    /// Places a precision prediction on the active round (Precision/Legends mode only)
+    /// predicted_price: price scaled to 4 decimals (e.g., 0.2297 → 2297)
+    /// While a reveal window is set, guesses must go through `commit_prediction` instead
+    pub fn place_precision_prediction(
+        env: Env,
+        user: Address,
+        amount: i128,
+        predicted_price: u128,
+    ) -> Result<(), ContractError> {
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidBetAmount);
+        }
+
+        // Validate price scale (must be 4 decimal places, max value 9999 for 0.9999)
+        // Reasonable max: 99999999 (9999.9999 XLM)
+        if predicted_price > 99_999_999 {
+            return Err(ContractError::InvalidPriceScale);
+        }
+
+        let round: Round = env.storage()
+            .persistent()
+            .get(&DataKey::ActiveRound)
+            .ok_or(ContractError::NoActiveRound)?;
+
+        // Verify round is in Precision mode
+        if round.mode != RoundMode::Precision {
+            return Err(ContractError::WrongModeForPrediction);
+        }
+
+        // With a reveal window configured, guesses must be sealed so they cannot be copied while betting is open
+        if Self::get_reveal_window(env.clone()).is_some() {
+            return Err(ContractError::WrongModeForPrediction);
+        }
+
+        let current_ledger = env.ledger().sequence();
+        if current_ledger >= round.bet_end_ledger {
+            return Err(ContractError::RoundEnded);
+        }
+
+        let user_balance = Self::_stake_balance(&env, &round, &user);
+        if user_balance < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        // Check if user already has a prediction in this round
+        let mut predictions: Vec<PrecisionPrediction> = env.storage()
+            .persistent()
+            .get(&DataKey::PrecisionPositions)
+            .unwrap_or(Vec::new(&env));
+
+        let commits = Self::_precision_commits(&env);
+        if commits.contains_key(user.clone()) {
+            return Err(ContractError::AlreadyBet);
+        }
+
+        let mut pool = amount
+            .checked_add(Self::_committed_stake(&commits)?)
+            .ok_or(ContractError::Overflow)?;
+        for i in 0..predictions.len() {
+            if let Some(pred) = predictions.get(i) {
+                if pred.user == user {
+                    return Err(ContractError::AlreadyBet);
+                }
+                pool = pool
+                    .checked_add(pred.amount)
+                    .ok_or(ContractError::Overflow)?;
+            }
+        }
+        Self::_check_bet_limits(&env, &round.mode, amount, pool)?;
+
+        // Deduct balance
+        let new_balance = user_balance
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_stake_balance(&env, &round, &user, new_balance)?;
+
+        // Store prediction
+        let prediction = PrecisionPrediction {
+            user: user.clone(),
+            predicted_price,
+            amount,
+        };
+        predictions.push_back(prediction);
+
+        env.storage().persistent().set(&DataKey::PrecisionPositions, &predictions);
+
+        // Emit event for precision prediction
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("predict"), symbol_short!("price")),
+            (user, predicted_price, round.start_ledger),
+        );
+
+        Ok(())
+    }
+
+    /// Stakes on a price-change bucket in the active round (Buckets mode only)
+    pub fn place_bucket_bet(env: Env, user: Address, amount: i128, bucket: u32) -> Result<(), ContractError> {
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidBetAmount);
+        }
+
+        let mut round: Round = env.storage()
+            .persistent()
+            .get(&DataKey::ActiveRound)
+            .ok_or(ContractError::NoActiveRound)?;
+
+        if round.mode != RoundMode::Buckets {
+            return Err(ContractError::WrongModeForPrediction);
+        }
+
+        let current_ledger = env.ledger().sequence();
+        if current_ledger >= round.bet_end_ledger {
+            return Err(ContractError::RoundEnded);
+        }
+
+        let bucket_pool = round.bucket_pools.get(bucket).ok_or(ContractError::InvalidBucket)?;
+
+        let user_balance = Self::_stake_balance(&env, &round, &user);
+        if user_balance < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        let mut positions: Map<Address, BucketPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::BucketPositions)
+            .unwrap_or(Map::new(&env));
+
+        if positions.contains_key(user.clone()) {
+            return Err(ContractError::AlreadyBet);
+        }
+
+        let pool = Self::_sum_pools(&round.bucket_pools)?
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_check_bet_limits(&env, &round.mode, amount, pool)?;
+
+        let new_balance = user_balance
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_stake_balance(&env, &round, &user, new_balance)?;
+
+        let bucket_pool = bucket_pool
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        round.bucket_pools.set(bucket, bucket_pool);
+
+        positions.set(user.clone(), BucketPosition { amount, bucket });
+
+        env.storage().persistent().set(&DataKey::BucketPositions, &positions);
+        env.storage().persistent().set(&DataKey::ActiveRound, &round);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("bucket"), symbol_short!("bet")),
+            (user, bucket, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Returns user's bucket stake in the current round (Buckets mode)
+    pub fn get_user_bucket_position(env: Env, user: Address) -> Option<BucketPosition> {
+        let positions: Map<Address, BucketPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::BucketPositions)
+            .unwrap_or(Map::new(&env));
+
+        positions.get(user)
+    }
+
+    /// Returns all bucket stakes for the current round
+    pub fn get_bucket_positions(env: Env) -> Map<Address, BucketPosition> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BucketPositions)
+            .unwrap_or(Map::new(&env))
+    }
+
+    fn _sum_pools(pools: &Vec<i128>) -> Result<i128, ContractError> {
+        let mut total: i128 = 0;
+        for pool in pools.iter() {
+            total = total
+                .checked_add(pool)
+                .ok_or(ContractError::Overflow)?;
+        }
+        Ok(total)
+    }
+
+    /// Places a sealed precision prediction during the bet window (Precision mode only)
+    /// hash: compute_commitment(user, predicted_price, salt); the stake is forfeited if never revealed
+    pub fn commit_prediction(
+        env: Env,
+        user: Address,
+        round_id: u32,
+        hash: BytesN<32>,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidBetAmount);
+        }
+
+        let round = Self::_precision_round(&env, round_id)?;
+
+        if env.ledger().sequence() >= round.bet_end_ledger {
+            return Err(ContractError::RoundEnded);
+        }
+
+        let user_balance = Self::_stake_balance(&env, &round, &user);
+        if user_balance < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        let mut commits = Self::_precision_commits(&env);
+        if commits.contains_key(user.clone()) {
+            return Err(ContractError::AlreadyBet);
+        }
+
+        let predictions = Self::get_precision_predictions(env.clone());
+        let mut pool = amount
+            .checked_add(Self::_committed_stake(&commits)?)
+            .ok_or(ContractError::Overflow)?;
+        for pred in predictions.iter() {
+            if pred.user == user {
+                return Err(ContractError::AlreadyBet);
+            }
+            pool = pool
+                .checked_add(pred.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+        Self::_check_bet_limits(&env, &round.mode, amount, pool)?;
+
+        let new_balance = user_balance
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_stake_balance(&env, &round, &user, new_balance)?;
+
+        commits.set(user.clone(), PrecisionCommit { hash, amount });
+        env.storage().persistent().set(&DataKey::PrecisionCommits, &commits);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("predict"), symbol_short!("commit")),
+            (user, round_id, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Reveals a sealed prediction after betting closes and before the reveal window ends
+    pub fn reveal_prediction(
+        env: Env,
+        user: Address,
+        round_id: u32,
+        predicted_price: u128,
+        salt: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        user.require_auth();
+
+        let round = Self::_precision_round(&env, round_id)?;
+
+        let current_ledger = env.ledger().sequence();
+        if current_ledger < round.bet_end_ledger {
+            return Err(ContractError::RevealNotOpen);
+        }
+        if current_ledger >= round.reveal_end_ledger {
+            return Err(ContractError::RevealWindowClosed);
+        }
+
+        let mut commits = Self::_precision_commits(&env);
+        let commit = commits.get(user.clone()).ok_or(ContractError::NoCommitment)?;
+
+        if Self::compute_commitment(env.clone(), user.clone(), predicted_price, salt) != commit.hash {
+            return Err(ContractError::CommitmentMismatch);
+        }
+
+        if predicted_price > 99_999_999 {
+            return Err(ContractError::InvalidPriceScale);
+        }
+
+        commits.remove(user.clone());
+        env.storage().persistent().set(&DataKey::PrecisionCommits, &commits);
+
+        let mut predictions = Self::get_precision_predictions(env.clone());
+        predictions.push_back(PrecisionPrediction {
+            user: user.clone(),
+            predicted_price,
+            amount: commit.amount,
+        });
+        env.storage().persistent().set(&DataKey::PrecisionPositions, &predictions);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("predict"), symbol_short!("price")),
+            (user, predicted_price, round.start_ledger),
+        );
+
+        Ok(())
+    }
+
+    /// Hash to commit for a sealed prediction:
+    /// sha256(user XDR || predicted_price as 16 big-endian bytes || salt)
+    pub fn compute_commitment(env: Env, user: Address, predicted_price: u128, salt: BytesN<32>) -> BytesN<32> {
+        let mut preimage: Bytes = user.to_xdr(&env);
+        preimage.extend_from_array(&predicted_price.to_be_bytes());
+        preimage.append(&salt.into());
+        env.crypto().sha256(&preimage).to_bytes()
+    }
+
+    /// Returns user's sealed, unrevealed prediction in the current round
+    pub fn get_user_commitment(env: Env, user: Address) -> Option<PrecisionCommit> {
+        Self::_precision_commits(&env).get(user)
+    }
+
+    /// Loads the active round, checking it matches `round_id` and is a Precision round
+    fn _precision_round(env: &Env, round_id: u32) -> Result<Round, ContractError> {
+        let round: Round = env.storage()
+            .persistent()
+            .get(&DataKey::ActiveRound)
+            .ok_or(ContractError::NoActiveRound)?;
+
+        if round.round_id != round_id {
+            return Err(ContractError::RoundMismatch);
+        }
+
+        if round.mode != RoundMode::Precision {
+            return Err(ContractError::WrongModeForPrediction);
+        }
+
+        Ok(round)
+    }
+
+    fn _precision_commits(env: &Env) -> Map<Address, PrecisionCommit> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PrecisionCommits)
+            .unwrap_or(Map::new(env))
+    }
+
+    fn _committed_stake(commits: &Map<Address, PrecisionCommit>) -> Result<i128, ContractError> {
+        let mut total: i128 = 0;
+        for (_, commit) in commits.iter() {
+            total = total
+                .checked_add(commit.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+        Ok(total)
+    }
+
+    /// Alias for place_precision_prediction - allows users to submit exact price predictions
+    /// guessed_price: price scaled to 4 decimals (e.g., 0.2297 → 2297)
+    pub fn predict_price(
+        env: Env,
+        user: Address,
+        guessed_price: u128,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        Self::place_precision_prediction(env, user, amount, guessed_price)
+    }
+    
+    /// Returns user's position in the current round (Up/Down mode)
+    pub fn get_user_position(env: Env, user: Address) -> Option<UserPosition> {
+        let positions: Map<Address, UserPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::UpDownPositions)
+            .unwrap_or(Map::new(&env));
+
+        positions.get(user)
+    }
+
+    /// Returns user's precision prediction in the current round (Precision mode)
+    pub fn get_user_precision_prediction(env: Env, user: Address) -> Option<PrecisionPrediction> {
+        let predictions: Vec<PrecisionPrediction> = env.storage()
+            .persistent()
+            .get(&DataKey::PrecisionPositions)
+            .unwrap_or(Vec::new(&env));
+
+        for i in 0..predictions.len() {
+            if let Some(pred) = predictions.get(i) {
+                if pred.user == user {
+                    return Some(pred);
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns all precision predictions for the current round
+    pub fn get_precision_predictions(env: Env) -> Vec<PrecisionPrediction> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PrecisionPositions)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns every prediction's rank, error and payout for a resolved Precision round
+    pub fn get_round_ranks(env: Env, round_id: u32) -> Vec<PrecisionRank> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RoundRanks(round_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns how a resolved round was settled
+    pub fn get_round_result(env: Env, round_id: u32) -> Option<RoundResult> {
+        env.storage().persistent().get(&DataKey::RoundResult(round_id))
+    }
+
+    /// Returns all Up/Down positions for the current round
+    pub fn get_updown_positions(env: Env) -> Map<Address, UserPosition> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::UpDownPositions)
+            .unwrap_or(Map::new(&env))
+    }
+    
+    /// Resolves the round with final price (oracle only)
+    /// Mode 0 (Up/Down): Winners split losers' pool proportionally; ties get refunds
+    /// Mode 1 (Precision/Legends): Closest guess wins full pot; ties split evenly
+    /// Mode 2 (Buckets): Stakes on the bucket containing the price change split the other buckets proportionally
+    pub fn resolve_round(env: Env, final_price: u128) -> Result<(), ContractError> {
+        if final_price == 0 {
+            return Err(ContractError::InvalidPrice);
+        }
+
+        let oracle: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Oracle)
+            .ok_or(ContractError::OracleNotSet)?;
+
+        oracle.require_auth();
+
+        let mut round: Round = env.storage()
+            .persistent()
+            .get(&DataKey::ActiveRound)
+            .ok_or(ContractError::NoActiveRound)?;
+
+        // Verify round has reached end_ledger
+        let current_ledger = env.ledger().sequence();
+        if current_ledger < round.end_ledger {
+            return Err(ContractError::RoundNotEnded);
+        }
+
+        // Claims for this round lapse after the configured claim window
+        // Tournament rounds pay chips directly, so they have nothing to claim
+        let claim_window = match round.tournament_id {
+            Some(_) => None,
+            None => env.storage().persistent().get::<_, u32>(&DataKey::ClaimWindowLedgers),
+        };
+        if let Some(window) = claim_window {
+            let deadline = current_ledger
+                .checked_add(window)
+                .ok_or(ContractError::Overflow)?;
+            env.storage().persistent().set(&DataKey::ClaimDeadline(round.round_id), &deadline);
+        }
+
+        // Whatever the round holds but resolution does not pay out is rounding dust
+        let staked = Self::_round_stake(&env, &round)?;
+        let settled_before = Self::_settled_supply(&env)?;
+
+        // Unrevealed sealed predictions are forfeited to the pot like a jackpot
+        let forfeited = Self::_committed_stake(&Self::_precision_commits(&env))?;
+        round.jackpot = round.jackpot
+            .checked_add(forfeited)
+            .ok_or(ContractError::Overflow)?;
+
+        // Branch based on round mode
+        let outcome = match round.mode {
+            RoundMode::UpDown => Self::_resolve_updown_mode(&env, &round, final_price)?,
+            RoundMode::Precision => Self::_resolve_precision_mode(&env, &round, final_price)?,
+            RoundMode::Buckets => Self::_resolve_buckets_mode(&env, &round, final_price)?,
+        };
+
+        // Roll the jackpot over if nobody won it
+        if outcome != RoundOutcome::Settled && round.jackpot > 0 {
+            Self::_roll_over(&env, &round, round.jackpot)?;
+        }
+
+        let settled = Self::_settled_supply(&env)?
+            .checked_sub(settled_before)
+            .ok_or(ContractError::Overflow)?;
+        Self::_adjust_supply(&env, TokenKey::Dust, staked - settled)?;
+
+        // Only rounds that left winnings to claim need sweeping
+        if env.storage().persistent().has(&DataKey::RoundClaimants(round.round_id)) {
+            let mut expiring = Self::get_expiring_rounds(env.clone());
+            expiring.push_back(round.round_id);
+            env.storage().persistent().set(&DataKey::ExpiringRounds, &expiring);
+        }
+
+        if let Some(tournament_id) = round.tournament_id {
+            let mut tournament = Self::_tournament(&env, tournament_id)?;
+            tournament.rounds_played = tournament.rounds_played
+                .checked_add(1)
+                .ok_or(ContractError::Overflow)?;
+            env.storage().persistent().set(&DataKey::Tournament(tournament_id), &tournament);
+            env.storage().persistent().remove(&DataKey::ActiveTournamentRound);
+        }
+
+        Self::_settle_betting_syndicates(&env)?;
+
+        if outcome == RoundOutcome::Voided {
+            #[allow(deprecated)]
+            env.events().publish(
+                (symbol_short!("round"), symbol_short!("voided")),
+                round.round_id,
+            );
+        }
+
+        let result = RoundResult {
+            round_id: round.round_id,
+            mode: round.mode.clone(),
+            price_start: round.price_start,
+            final_price,
+            outcome,
+            resolved_ledger: current_ledger,
+        };
+        env.storage().persistent().set(&DataKey::RoundResult(round.round_id), &result);
+
+        // Clean up storage
+        env.storage().persistent().remove(&DataKey::ActiveRound);
+        env.storage().persistent().remove(&DataKey::Positions);
+        env.storage().persistent().remove(&DataKey::UpDownPositions);
+        env.storage().persistent().remove(&DataKey::PrecisionPositions);
+        env.storage().persistent().remove(&DataKey::BucketPositions);
+        env.storage().persistent().remove(&DataKey::PrecisionCommits);
+
+        // Emit resolution event
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("round"), symbol_short!("resolved")),
+            final_price,
+        );
+
+        Ok(())
+    }
+
+    /// Resolves Up/Down mode round
+    /// Rounds where only one side bet are voided: every stake is refunded and stats are untouched
+    fn _resolve_updown_mode(env: &Env, round: &Round, final_price: u128) -> Result<RoundOutcome, ContractError> {
+        let positions: Map<Address, UserPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::UpDownPositions)
+            .unwrap_or(Map::new(env));
+
+        if positions.is_empty() {
+            return Ok(RoundOutcome::Empty);
+        }
+
+        if final_price == round.price_start {
+            Self::_record_refunds(env, round, positions)?;
+            return Ok(RoundOutcome::Refunded);
+        }
+
+        if round.pool_up == 0 || round.pool_down == 0 {
+            Self::_record_refunds(env, round, positions)?;
+            return Ok(RoundOutcome::Voided);
+        }
+
+        if final_price > round.price_start {
+            Self::_record_winnings(env, round, positions, BetSide::Up, round.pool_up, round.pool_down)?;
+        } else {
+            Self::_record_winnings(env, round, positions, BetSide::Down, round.pool_down, round.pool_up)?;
+        }
+        Ok(RoundOutcome::Settled)
+    }
+
+    /// Resolves Precision/Legends mode round
+    /// Awards full pot to closest guess(es); ties (including guesses within the tie band) split evenly
+    /// Exact hits first draw their bonus from the share of the pot reserved for it
+    fn _resolve_precision_mode(env: &Env, round: &Round, final_price: u128) -> Result<RoundOutcome, ContractError> {
+        let predictions: Vec<PrecisionPrediction> = env.storage()
+            .persistent()
+            .get(&DataKey::PrecisionPositions)
+            .unwrap_or(Vec::new(env));
+
+        // If no predictions, nothing to resolve
+        if predictions.is_empty() {
+            return Ok(RoundOutcome::Empty);
+        }
+
+        let band = match round.tie_rules.tolerance {
+            Tolerance::Exact => 0,
+            Tolerance::Units(units) => units,
+            Tolerance::Bps(bps) => final_price
+                .checked_mul(bps as u128)
+                .ok_or(ContractError::Overflow)?
+                / 10_000,
+        };
+        let mut ranked = Self::_rank_predictions(env, &predictions, final_price, band)?;
+
+        // Closest guess(es) win, or everyone ranked within a tiered payout table
+        let paid_ranks = match &round.precision_payout {
+            PrecisionPayout::Tiered(tiers) => tiers.len(),
+            _ => 1,
+        };
+        let mut winners: Vec<PrecisionPrediction> = Vec::new(env);
+        for entry in ranked.iter() {
+            if entry.rank <= paid_ranks {
+                winners.push_back(PrecisionPrediction {
+                    user: entry.user,
+                    predicted_price: entry.predicted_price,
+                    amount: entry.amount,
+                });
+            }
+        }
+
+        // Calculate total pot
+        let mut total_pot: i128 = 0;
+        for i in 0..predictions.len() {
+            if let Some(pred) = predictions.get(i) {
+                total_pot = total_pot
+                    .checked_add(pred.amount)
+                    .ok_or(ContractError::Overflow)?;
+            }
+        }
+
+        if winners.is_empty() || total_pot <= 0 {
+            return Ok(RoundOutcome::Empty);
+        }
+
+        // Protocol fee is charged on the losers' stakes only
+        let mut winners_stake: i128 = 0;
+        for i in 0..winners.len() {
+            if let Some(winner) = winners.get(i) {
+                winners_stake = winners_stake
+                    .checked_add(winner.amount)
+                    .ok_or(ContractError::Overflow)?;
+            }
+        }
+        let losers_stake = total_pot
+            .checked_sub(winners_stake)
+            .ok_or(ContractError::Overflow)?;
+        let fee = Self::_calculate_fee(env, round, losers_stake)?;
+        let mut referral_rewards: i128 = 0;
+        for winner in winners.iter() {
+            referral_rewards += Self::_pay_referral(env, &winner.user, fee, winner.amount, winners_stake)?;
+        }
+        Self::_credit_treasury(env, fee - referral_rewards)?;
+
+        let mut distributable = total_pot
+            .checked_sub(fee)
+            .and_then(|d| d.checked_add(round.jackpot))
+            .ok_or(ContractError::Overflow)?;
+
+        match &round.precision_payout {
+            PrecisionPayout::Even => {
+                distributable -= Self::_pay_perfect_bonus(env, round, &ranked, distributable)?;
+                Self::_pay_even(env, round, &winners, distributable)?
+            },
+            PrecisionPayout::StakeWeighted(weighting) => {
+                let mut losers_pot = losers_stake
+                    .checked_sub(fee)
+                    .ok_or(ContractError::Overflow)?;
+
+                // Winners get their stakes back, so the bonus is funded from the jackpot, then losers' pot
+                let mut funded = round.clone();
+                let available = losers_pot
+                    .checked_add(round.jackpot)
+                    .ok_or(ContractError::Overflow)?;
+                let bonus = Self::_pay_perfect_bonus(env, round, &ranked, available)?;
+                let from_jackpot = bonus.min(round.jackpot);
+                funded.jackpot -= from_jackpot;
+                losers_pot -= bonus - from_jackpot;
+
+                Self::_pay_stake_weighted(
+                    env,
+                    &funded,
+                    &predictions,
+                    &winners,
+                    weighting,
+                    winners_stake,
+                    losers_stake,
+                    losers_pot,
+                )?;
+            },
+            PrecisionPayout::Tiered(tiers) => {
+                distributable -= Self::_pay_perfect_bonus(env, round, &ranked, distributable)?;
+                Self::_pay_tiers(env, round, &ranked, tiers, distributable)?
+            },
+            PrecisionPayout::Scored(scoring) => {
+                distributable -= Self::_pay_perfect_bonus(env, round, &ranked, distributable)?;
+                Self::_pay_scored(env, round, &ranked, &winners, scoring, distributable)?
+            },
+        }
+
+        // Record each prediction's rank and what it was credited, and update stats
+        // Scored rounds count a win when the payout beats the stake
+        for i in 0..ranked.len() {
+            if let Some(mut entry) = ranked.get(i) {
+                entry.payout = env.storage()
+                    .persistent()
+                    .get(&DataKey::RoundWinnings(entry.user.clone(), round.round_id))
+                    .unwrap_or(0);
+
+                let won = match &round.precision_payout {
+                    PrecisionPayout::Scored(_) => entry.payout > entry.amount,
+                    _ => entry.rank <= paid_ranks,
+                };
+                let (user, mode) = (entry.user.clone(), &round.mode);
+                if Self::_tracks_stats(round) {
+                    if won {
+                        Self::_update_stats_win(env, user, mode, entry.amount, entry.payout, Some(entry.error));
+                    } else {
+                        Self::_update_stats_loss(env, user, mode, entry.amount, entry.payout, Some(entry.error));
+                    }
+                }
+
+                ranked.set(i, entry);
+            }
+        }
+        env.storage().persistent().set(&DataKey::RoundRanks(round.round_id), &ranked);
+
+        Ok(RoundOutcome::Settled)
+    }
+
+    /// Orders predictions by absolute error, closest first, with competition ranks (1, 2, 2, 4)
+    /// Errors within `band` of the closest guess share first place; ties keep placement order
+    fn _rank_predictions(
+        env: &Env,
+        predictions: &Vec<PrecisionPrediction>,
+        final_price: u128,
+        band: u128,
+    ) -> Result<Vec<PrecisionRank>, ContractError> {
+        let mut ranked: Vec<PrecisionRank> = Vec::new(env);
+
+        for pred in predictions.iter() {
+            let error = pred.predicted_price.abs_diff(final_price);
+
+            let mut index = ranked.len();
+            while index > 0 && ranked.get(index - 1).is_some_and(|prev| prev.error > error) {
+                index -= 1;
+            }
+            ranked.insert(index, PrecisionRank {
+                user: pred.user,
+                predicted_price: pred.predicted_price,
+                amount: pred.amount,
+                error,
+                rank: 0,
+                payout: 0,
+            });
+        }
+
+        let first_place = ranked.get(0).map(|entry| entry.error.saturating_add(band)).unwrap_or(0);
+        let mut rank = 0;
+        let mut prev_error: Option<u128> = None;
+        for i in 0..ranked.len() {
+            if let Some(mut entry) = ranked.get(i) {
+                if entry.error <= first_place {
+                    rank = 1;
+                } else if prev_error != Some(entry.error) {
+                    rank = i + 1;
+                }
+                prev_error = Some(entry.error);
+                entry.rank = rank;
+                ranked.set(i, entry);
+            }
+        }
+
+        Ok(ranked)
+    }
+
+    /// Credits each exact hit stake * bonus_multiplier_bps, funded from bonus_pool_bps of `pot`
+    /// Bonuses are scaled down pro-rata when the fund cannot cover them all
+    /// Returns the total bonus paid
+    fn _pay_perfect_bonus(
+        env: &Env,
+        round: &Round,
+        ranked: &Vec<PrecisionRank>,
+        pot: i128,
+    ) -> Result<i128, ContractError> {
+        let rules = &round.tie_rules;
+        if rules.bonus_multiplier_bps == 0 || rules.bonus_pool_bps == 0 {
+            return Ok(0);
+        }
+
+        let fund = pot
+            .checked_mul(rules.bonus_pool_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / 10_000;
+
+        let mut wanted: i128 = 0;
+        for entry in ranked.iter().filter(|entry| entry.error == 0) {
+            wanted = entry.amount
+                .checked_mul(rules.bonus_multiplier_bps as i128)
+                .map(|bonus| bonus / 10_000)
+                .and_then(|bonus| wanted.checked_add(bonus))
+                .ok_or(ContractError::Overflow)?;
+        }
+        if wanted == 0 || fund == 0 {
+            return Ok(0);
+        }
+
+        let mut paid: i128 = 0;
+        for entry in ranked.iter().filter(|entry| entry.error == 0) {
+            let mut bonus = entry.amount * rules.bonus_multiplier_bps as i128 / 10_000;
+            if wanted > fund {
+                bonus = fund
+                    .checked_mul(bonus)
+                    .ok_or(ContractError::Overflow)?
+                    / wanted;
+            }
+            if bonus > 0 {
+                Self::_credit_winnings(env, &entry.user, round, bonus)?;
+                paid += bonus;
+
+                #[allow(deprecated)]
+                env.events().publish(
+                    (symbol_short!("predict"), symbol_short!("perfect")),
+                    (entry.user, round.round_id, bonus),
+                );
+            }
+        }
+
+        Ok(paid)
+    }
+
+    /// Pays ranked predictions from a table of bps
+    /// Tied predictions pool the tiers their positions span and split them evenly; tiers
+    /// with nobody to fill them are shared out in proportion to the occupied tiers
+    fn _pay_tiers(
+        env: &Env,
+        round: &Round,
+        ranked: &Vec<PrecisionRank>,
+        tiers: &Vec<u32>,
+        distributable: i128,
+    ) -> Result<(), ContractError> {
+        let occupied = ranked.len().min(tiers.len());
+        let mut occupied_weight: i128 = 0;
+        for t in 0..occupied {
+            occupied_weight += tiers.get(t).unwrap_or(0) as i128;
+        }
+
+        let mut start = 0;
+        while start < occupied {
+            let rank = ranked.get(start).map(|entry| entry.rank).unwrap_or(0);
+            let mut end = start;
+            while end < ranked.len() && ranked.get(end).is_some_and(|entry| entry.rank == rank) {
+                end += 1;
+            }
+
+            let mut group_weight: i128 = 0;
+            for t in start..end.min(tiers.len()) {
+                group_weight += tiers.get(t).unwrap_or(0) as i128;
+            }
+            let members = (end - start) as i128;
+            let share = distributable
+                .checked_mul(group_weight)
+                .ok_or(ContractError::Overflow)?
+                / (occupied_weight * members);
+
+            for i in start..end {
+                if let Some(entry) = ranked.get(i) {
+                    Self::_credit_winnings(env, &entry.user, round, share)?;
+                }
+            }
+
+            start = end;
+        }
+
+        Ok(())
+    }
+
+    /// Splits the pot evenly among the closest guess(es)
+    fn _pay_even(
+        env: &Env,
+        round: &Round,
+        winners: &Vec<PrecisionPrediction>,
+        distributable: i128,
+    ) -> Result<(), ContractError> {
+        let winner_count = winners.len() as i128;
+        let payout_per_winner = distributable / winner_count;
+
+        for i in 0..winners.len() {
+            if let Some(winner) = winners.get(i) {
+                Self::_credit_winnings(env, &winner.user, round, payout_per_winner)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Splits the pot in proportion to stake * score(error)
+    /// Falls back to an even split among the closest guess(es) if every score rounds to zero
+    fn _pay_scored(
+        env: &Env,
+        round: &Round,
+        ranked: &Vec<PrecisionRank>,
+        winners: &Vec<PrecisionPrediction>,
+        scoring: &Scoring,
+        distributable: i128,
+    ) -> Result<(), ContractError> {
+        let mut weights: Vec<i128> = Vec::new(env);
+        let mut total_weight: i128 = 0;
+        for entry in ranked.iter() {
+            let weight = entry.amount
+                .checked_mul(Self::_score(scoring, entry.error))
+                .ok_or(ContractError::Overflow)?;
+            total_weight = total_weight
+                .checked_add(weight)
+                .ok_or(ContractError::Overflow)?;
+            weights.push_back(weight);
+        }
+
+        if total_weight == 0 {
+            return Self::_pay_even(env, round, winners, distributable);
+        }
+
+        for i in 0..ranked.len() {
+            if let (Some(entry), Some(weight)) = (ranked.get(i), weights.get(i)) {
+                let payout = distributable
+                    .checked_mul(weight)
+                    .ok_or(ContractError::Overflow)?
+                    / total_weight;
+                if payout > 0 {
+                    Self::_credit_winnings(env, &entry.user, round, payout)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Score in [0, SCORE_SCALE] for a prediction's absolute error
+    pub(crate) fn _score(scoring: &Scoring, error: u128) -> i128 {
+        let width = scoring.width as i128;
+        match scoring.kernel {
+            ScoringKernel::InverseDistance => {
+                // Errors this large score below one unit anyway
+                if error > scoring.width.saturating_mul(SCORE_SCALE as u128) {
+                    return 0;
+                }
+                SCORE_SCALE * width / (width + error as i128)
+            },
+            ScoringKernel::Gaussian => {
+                if error > scoring.width.saturating_mul(10) {
+                    return 0;
+                }
+                // x = error^2 / (2 * width^2), at SCORE_SCALE
+                let error = error as i128;
+                let x = error * error * SCORE_SCALE / (2 * width * width);
+                Self::_exp_neg(x)
+            },
+        }
+    }
+
+    /// e^(-x) for x >= 0, both at SCORE_SCALE
+    /// Splits x into whole and fractional parts: e^-whole by repeated multiplication,
+    /// e^-frac as the reciprocal of a Taylor series that converges quickly on [0, 1)
+    fn _exp_neg(x: i128) -> i128 {
+        let whole = x / SCORE_SCALE;
+        let frac = x % SCORE_SCALE;
+
+        let mut term = SCORE_SCALE;
+        let mut exp_frac = SCORE_SCALE;
+        for k in 1..=10 {
+            term = term * frac / (SCORE_SCALE * k);
+            exp_frac += term;
+        }
+
+        let mut result = SCORE_SCALE * SCORE_SCALE / exp_frac;
+        for _ in 0..whole {
+            result = result * EXP_NEG_ONE / SCORE_SCALE;
+            if result == 0 {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Pays winners in proportion to stake, each winning at most `max_win_bps` per unit staked
+    /// Winnings draw on the jackpot first, then the losers' pot (after fee). Unclaimed jackpot
+    /// rolls over; unclaimed losers' pot is refunded to losers pro-rata or rolled over
+    #[allow(clippy::too_many_arguments)]
+    fn _pay_stake_weighted(
+        env: &Env,
+        round: &Round,
+        predictions: &Vec<PrecisionPrediction>,
+        winners: &Vec<PrecisionPrediction>,
+        weighting: &StakeWeighting,
+        winners_stake: i128,
+        losers_stake: i128,
+        losers_pot: i128,
+    ) -> Result<(), ContractError> {
+        let cap = winners_stake
+            .checked_mul(weighting.max_win_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / 10_000;
+        let available = losers_pot
+            .checked_add(round.jackpot)
+            .ok_or(ContractError::Overflow)?;
+        let winnable = available.min(cap);
+
+        for i in 0..winners.len() {
+            if let Some(winner) = winners.get(i) {
+                let payout = Self::_winner_payout(winner.amount, winners_stake, winnable)?;
+                Self::_credit_winnings(env, &winner.user, round, payout)?;
+            }
+        }
+
+        let jackpot_used = winnable.min(round.jackpot);
+        let jackpot_left = round.jackpot - jackpot_used;
+        let pot_left = losers_pot - (winnable - jackpot_used);
+
+        Self::_roll_over(env, round, jackpot_left)?;
+
+        if pot_left > 0 {
+            match weighting.remainder {
+                RemainderPolicy::Refund => {
+                    for i in 0..predictions.len() {
+                        if let Some(pred) = predictions.get(i) {
+                            if winners.iter().any(|w| w.user == pred.user) {
+                                continue;
+                            }
+                            let refund = pred.amount
+                                .checked_mul(pot_left)
+                                .ok_or(ContractError::Overflow)?
+                                / losers_stake;
+                            if refund > 0 {
+                                Self::_credit_winnings(env, &pred.user, round, refund)?;
+                            }
+                        }
+                    }
+                },
+                RemainderPolicy::Rollover => Self::_roll_over(env, round, pot_left)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Claims all pending winnings and adds them to balance
+    /// Emits one claim event per round the payout came from; lapsed rounds are left for sweeping
+    pub fn claim_winnings(env: Env, user: Address) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        let mut claimed = Self::_claim_open_rounds(&env, &user, u32::MAX)?;
+
+        // Winnings recorded before per-round tracking are reported as round 0
+        let pending = Self::get_pending_winnings(env.clone(), user.clone());
+        let mut attributed: i128 = 0;
+        for claim in Self::get_claimable_rounds(env.clone(), user.clone()).iter() {
+            attributed = attributed
+                .checked_add(claim.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+        let unattributed = pending
+            .checked_sub(attributed)
+            .ok_or(ContractError::Overflow)?;
+        if unattributed > 0 {
+            Self::_pay_out(&env, &user, unattributed)?;
+            Self::_emit_claim(&env, &user, 0, unattributed);
+            claimed = claimed
+                .checked_add(unattributed)
+                .ok_or(ContractError::Overflow)?;
+        }
+
+        Ok(claimed)
+    }
+
+    /// Claims the winnings from a single round and adds them to balance
+    pub fn claim_round(env: Env, user: Address, round_id: u32) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        let rounds_key = DataKey::ClaimableRounds(user.clone());
+        let round_ids: Vec<u32> = env.storage().persistent().get(&rounds_key).unwrap_or(Vec::new(&env));
+        let index = round_ids
+            .first_index_of(round_id)
+            .ok_or(ContractError::NoWinningsForRound)?;
+
+        if Self::_is_claim_expired(&env, round_id) {
+            return Err(ContractError::ClaimExpired);
+        }
+
+        let amount = Self::_take_round_winnings(&env, &user, round_id);
+        Self::_pay_out(&env, &user, amount)?;
+
+        let mut remaining = round_ids;
+        remaining.remove(index);
+        Self::_set_claimable_rounds(&env, &user, &remaining);
+
+        Self::_emit_claim(&env, &user, round_id, amount);
+
+        Ok(amount)
+    }
+
+    /// Claims winnings from up to `max_rounds` rounds, oldest first
+    /// Returns the total amount claimed; call again to claim any remaining rounds
+    pub fn claim_all(env: Env, user: Address, max_rounds: u32) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        Self::_claim_open_rounds(&env, &user, max_rounds)
+    }
+
+    /// Claims up to `max_rounds` rounds whose claim window has not lapsed
+    fn _claim_open_rounds(env: &Env, user: &Address, max_rounds: u32) -> Result<i128, ContractError> {
+        let rounds_key = DataKey::ClaimableRounds(user.clone());
+        let round_ids: Vec<u32> = env.storage().persistent().get(&rounds_key).unwrap_or(Vec::new(env));
+
+        let mut remaining: Vec<u32> = Vec::new(env);
+        let mut claimed_rounds: u32 = 0;
+        let mut total: i128 = 0;
+        for round_id in round_ids.iter() {
+            if claimed_rounds >= max_rounds || Self::_is_claim_expired(env, round_id) {
+                remaining.push_back(round_id);
+                continue;
+            }
+
+            let amount = Self::_take_round_winnings(env, user, round_id);
+            total = total
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?;
+            claimed_rounds += 1;
+            Self::_emit_claim(env, user, round_id, amount);
+        }
+
+        Self::_pay_out(env, user, total)?;
+        Self::_set_claimable_rounds(env, user, &remaining);
+
+        Ok(total)
+    }
+
+    /// Adds a (possibly negative) amount to the user's pending winnings and the pending supply
+    fn _adjust_pending(env: &Env, user: &Address, delta: i128) -> Result<(), ContractError> {
+        let key = DataKey::PendingWinnings(user.clone());
+        let pending: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let new_pending = pending
+            .checked_add(delta)
+            .ok_or(ContractError::Overflow)?;
+        if new_pending == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &new_pending);
+        }
+
+        Self::_adjust_supply(env, TokenKey::Pending, delta)
+    }
+
+    /// Moves an amount out of pending winnings and into the user's balance
+    fn _pay_out(env: &Env, user: &Address, amount: i128) -> Result<(), ContractError> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let new_balance = Self::balance(env.clone(), user.clone())
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+
+        Self::_set_balance(env, user.clone(), new_balance)?;
+        Self::_adjust_pending(env, user, -amount)
+    }
+
+    /// Removes and returns a user's unclaimed payout for a round
+    /// Once the last claimant of a round with a deadline is paid, the round no longer expires
+    fn _take_round_winnings(env: &Env, user: &Address, round_id: u32) -> i128 {
+        let key = DataKey::RoundWinnings(user.clone(), round_id);
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().remove(&key);
+
+        let claimants_key = DataKey::RoundClaimants(round_id);
+        if let Some(mut claimants) = env.storage().persistent().get::<_, Vec<Address>>(&claimants_key) {
+            if let Some(index) = claimants.first_index_of(user.clone()) {
+                claimants.remove(index);
+            }
+            if claimants.is_empty() {
+                env.storage().persistent().remove(&claimants_key);
+                let mut expiring = Self::get_expiring_rounds(env.clone());
+                if let Some(index) = expiring.first_index_of(round_id) {
+                    expiring.remove(index);
+                    env.storage().persistent().set(&DataKey::ExpiringRounds, &expiring);
+                }
+            } else {
+                env.storage().persistent().set(&claimants_key, &claimants);
+            }
+        }
+
+        amount
+    }
+
+    fn _set_claimable_rounds(env: &Env, user: &Address, round_ids: &Vec<u32>) {
+        let key = DataKey::ClaimableRounds(user.clone());
+        if round_ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, round_ids);
+        }
+    }
+
+    fn _emit_claim(env: &Env, user: &Address, round_id: u32, amount: i128) {
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("claim"), user.clone()),
+            (round_id, amount),
+        );
+    }
+
+    /// Sets how many ledgers after resolution winnings stay claimable (admin only)
+    /// 0 disables the deadline for rounds resolved from now on
+    pub fn set_claim_window(env: Env, ledgers: u32) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if ledgers == 0 {
+            env.storage().persistent().remove(&DataKey::ClaimWindowLedgers);
+        } else {
+            env.storage().persistent().set(&DataKey::ClaimWindowLedgers, &ledgers);
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("claimwin"), symbol_short!("updated")),
+            ledgers,
+        );
+
+        Ok(())
+    }
+
+    /// Returns the claim window in ledgers, if deadlines are enabled
+    pub fn get_claim_window(env: Env) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::ClaimWindowLedgers)
+    }
+
+    /// Returns the last ledger a round's winnings can be claimed, if it has a deadline
+    pub fn get_claim_deadline(env: Env, round_id: u32) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::ClaimDeadline(round_id))
+    }
+
+    /// Returns the user's unclaimed winnings that have a deadline, oldest first
+    /// Includes lapsed claims that have not been swept yet
+    pub fn get_expiring_claims(env: Env, user: Address) -> Vec<ExpiringClaim> {
+        let mut claims = Vec::new(&env);
+        for claim in Self::get_claimable_rounds(env.clone(), user).iter() {
+            if let Some(deadline_ledger) = Self::get_claim_deadline(env.clone(), claim.round_id) {
+                claims.push_back(ExpiringClaim {
+                    round_id: claim.round_id,
+                    amount: claim.amount,
+                    deadline_ledger,
+                });
+            }
+        }
+        claims
+    }
+
+    /// Returns rounds with a claim deadline whose winnings are not all claimed or swept yet
+    pub fn get_expiring_rounds(env: Env) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExpiringRounds)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns swept funds waiting to be added to the next round
+    pub fn get_jackpot(env: Env) -> i128 {
+        env.storage().persistent().get(&DataKey::Jackpot).unwrap_or(0)
+    }
+
+    /// Sweeps a lapsed round's unclaimed winnings to the treasury or next-round jackpot (admin only)
+    /// Returns the amount swept
+    pub fn sweep_unclaimed(env: Env, round_id: u32, destination: SweepDestination) -> Result<i128, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        let deadline: u32 = env.storage()
+            .persistent()
+            .get(&DataKey::ClaimDeadline(round_id))
+            .ok_or(ContractError::NoClaimDeadline)?;
+        if env.ledger().sequence() <= deadline {
+            return Err(ContractError::ClaimWindowOpen);
+        }
+
+        let claimants: Vec<Address> = env.storage()
+            .persistent()
+            .get(&DataKey::RoundClaimants(round_id))
+            .unwrap_or(Vec::new(&env));
+
+        let mut total: i128 = 0;
+        for user in claimants.iter() {
+            let amount = Self::_take_round_winnings(&env, &user, round_id);
+            if amount == 0 {
+                continue;
+            }
+
+            Self::_adjust_pending(&env, &user, -amount)?;
+
+            let mut round_ids: Vec<u32> = env.storage()
+                .persistent()
+                .get(&DataKey::ClaimableRounds(user.clone()))
+                .unwrap_or(Vec::new(&env));
+            if let Some(index) = round_ids.first_index_of(round_id) {
+                round_ids.remove(index);
+            }
+            Self::_set_claimable_rounds(&env, &user, &round_ids);
+
+            total = total
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+
+        match destination {
+            SweepDestination::Treasury => Self::_credit_treasury(&env, total)?,
+            SweepDestination::Jackpot => Self::_add_to_jackpot(&env, total)?,
+        }
+
+        env.storage().persistent().remove(&DataKey::RoundClaimants(round_id));
+        env.storage().persistent().remove(&DataKey::ClaimDeadline(round_id));
+
+        let mut expiring = Self::get_expiring_rounds(env.clone());
+        if let Some(index) = expiring.first_index_of(round_id) {
+            expiring.remove(index);
+        }
+        env.storage().persistent().set(&DataKey::ExpiringRounds, &expiring);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("sweep"), round_id),
+            (total, destination),
+        );
+
+        Ok(total)
+    }
+
+    /// Returns true once the round's claim deadline has passed
+    fn _is_claim_expired(env: &Env, round_id: u32) -> bool {
+        match env.storage().persistent().get::<_, u32>(&DataKey::ClaimDeadline(round_id)) {
+            Some(deadline) => env.ledger().sequence() > deadline,
+            None => false,
+        }
+    }
+
+    fn _add_to_jackpot(env: &Env, amount: i128) -> Result<(), ContractError> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let jackpot: i128 = env.storage().persistent().get(&DataKey::Jackpot).unwrap_or(0);
+        let new_jackpot = jackpot
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Jackpot, &new_jackpot);
+
+        Ok(())
+    }
+
+    /// Carries an unwon amount into the next round: the tournament's own jackpot for
+    /// tournament rounds, the shared jackpot otherwise
+    fn _roll_over(env: &Env, round: &Round, amount: i128) -> Result<(), ContractError> {
+        let tournament_id = match round.tournament_id {
+            Some(tournament_id) => tournament_id,
+            None => return Self::_add_to_jackpot(env, amount),
+        };
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let mut tournament = Self::_tournament(env, tournament_id)?;
+        tournament.jackpot = tournament.jackpot
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Tournament(tournament_id), &tournament);
+
+        Ok(())
+    }
+
+    /// Credits a payout to the user's pending winnings, attributed to a round
+    /// Payouts in a tournament round go straight back to the player's chips instead
+    fn _credit_winnings(env: &Env, user: &Address, round: &Round, amount: i128) -> Result<(), ContractError> {
+        if amount <= 0 {
+            return Ok(());
+        }
+
+        let round_id = round.round_id;
+        if let Some(tournament_id) = round.tournament_id {
+            let key = DataKey::TournamentChips(tournament_id, user.clone());
+            let chips: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            let new_chips = chips
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?;
+            env.storage().persistent().set(&key, &new_chips);
+            return Ok(());
+        }
+
+        Self::_adjust_pending(env, user, amount)?;
+
+        let round_key = DataKey::RoundWinnings(user.clone(), round_id);
+        let existing_round: i128 = env.storage().persistent().get(&round_key).unwrap_or(0);
+        let new_round = existing_round
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&round_key, &new_round);
+
+        if existing_round == 0 {
+            let rounds_key = DataKey::ClaimableRounds(user.clone());
+            let mut round_ids: Vec<u32> = env.storage().persistent().get(&rounds_key).unwrap_or(Vec::new(env));
+            round_ids.push_back(round_id);
+            env.storage().persistent().set(&rounds_key, &round_ids);
+
+            // Rounds with a deadline remember who to sweep
+            if env.storage().persistent().has(&DataKey::ClaimDeadline(round_id)) {
+                let claimants_key = DataKey::RoundClaimants(round_id);
+                let mut claimants: Vec<Address> = env.storage().persistent().get(&claimants_key).unwrap_or(Vec::new(env));
+                claimants.push_back(user.clone());
+                env.storage().persistent().set(&claimants_key, &claimants);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolves Buckets mode round
+    /// Winning bucket splits the other buckets' stakes proportionally; an empty winning bucket voids the round
+    fn _resolve_buckets_mode(env: &Env, round: &Round, final_price: u128) -> Result<RoundOutcome, ContractError> {
+        let positions: Map<Address, BucketPosition> = env.storage()
+            .persistent()
+            .get(&DataKey::BucketPositions)
+            .unwrap_or(Map::new(env));
+
+        if positions.is_empty() {
+            return Ok(RoundOutcome::Empty);
+        }
+
+        let winning_bucket = Self::_bucket_for_price(round, final_price)?;
+        let winning_pool = round.bucket_pools.get(winning_bucket).unwrap_or(0);
+
+        if winning_pool == 0 {
+            for (user, position) in positions.iter() {
+                Self::_credit_winnings(env, &user, round, position.amount)?;
+                if Self::_tracks_stats(round) {
+                    Self::_update_stats_refund(env, user);
+                }
+            }
+            return Ok(RoundOutcome::Voided);
+        }
+
+        let losing_pool = Self::_sum_pools(&round.bucket_pools)?
+            .checked_sub(winning_pool)
+            .ok_or(ContractError::Overflow)?;
+        let fee = Self::_calculate_fee(env, round, losing_pool)?;
+        let distributable = Self::_distributable_pool(env, round, losing_pool)?;
+
+        let mut referral_rewards: i128 = 0;
+        for (user, position) in positions.iter() {
+            if position.bucket == winning_bucket {
+                let payout = Self::_winner_payout(position.amount, winning_pool, distributable)?;
+                Self::_credit_winnings(env, &user, round, payout)?;
+                referral_rewards += Self::_pay_referral(env, &user, fee, position.amount, winning_pool)?;
+                if Self::_tracks_stats(round) {
+                    Self::_update_stats_win(env, user, &round.mode, position.amount, payout, None);
+                }
+            } else if Self::_tracks_stats(round) {
+                Self::_update_stats_loss(env, user, &round.mode, position.amount, 0, None);
+            }
+        }
+        Self::_credit_treasury(env, fee - referral_rewards)?;
+
+        Ok(RoundOutcome::Settled)
+    }
+
+    /// Index of the bucket containing the change from price_start to final_price
+    /// Change is floored to whole basis points so a bucket never includes values below its lower bound
+    fn _bucket_for_price(round: &Round, final_price: u128) -> Result<u32, ContractError> {
+        let start = i128::try_from(round.price_start).map_err(|_| ContractError::Overflow)?;
+        let end = i128::try_from(final_price).map_err(|_| ContractError::Overflow)?;
+        let diff = end
+            .checked_sub(start)
+            .ok_or(ContractError::Overflow)?;
+
+        // floor(diff * 10_000 / start), split so only changes far beyond any bound saturate
+        let change_bps = diff
+            .div_euclid(start)
+            .saturating_mul(10_000)
+            .saturating_add(diff.rem_euclid(start) * 10_000 / start);
+
+        let mut bucket = 0;
+        for bound in round.bucket_bounds.iter() {
+            if change_bps < bound as i128 {
+                break;
+            }
+            bucket += 1;
+        }
+        Ok(bucket)
+    }
+
+    /// Records refunds when price unchanged or the round is voided
+    fn _record_refunds(env: &Env, round: &Round, positions: Map<Address, UserPosition>) -> Result<(), ContractError> {
+        let keys: Vec<Address> = positions.keys();
+        
+        for i in 0..keys.len() {
+            if let Some(user) = keys.get(i) {
+                if let Some(position) = positions.get(user.clone()) {
+                    Self::_credit_winnings(env, &user, round, position.amount)?;
+                    if Self::_tracks_stats(round) {
+                        Self::_update_stats_refund(env, user);
+                    }
+                }
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Records winnings for winning side
+    /// Formula: payout = bet + (bet / winning_pool) * (losing_pool - fee + jackpot)
+    /// Callers void the round instead when the winning pool is empty
+    fn _record_winnings(
+        env: &Env,
+        round: &Round,
+        positions: Map<Address, UserPosition>,
+        winning_side: BetSide,
+        winning_pool: i128,
+        losing_pool: i128,
+    ) -> Result<(), ContractError> {
+        let fee = Self::_calculate_fee(env, round, losing_pool)?;
+        let losing_pool = Self::_distributable_pool(env, round, losing_pool)?;
+
+        let keys: Vec<Address> = positions.keys();
+        let mut referral_rewards: i128 = 0;
+        
+        for i in 0..keys.len() {
+            if let Some(user) = keys.get(i) {
+                if let Some(position) = positions.get(user.clone()) {
+                    if position.side == winning_side {
+                        let payout = Self::_winner_payout(position.amount, winning_pool, losing_pool)?;
+                        
+                        Self::_credit_winnings(env, &user, round, payout)?;
+                        referral_rewards += Self::_pay_referral(env, &user, fee, position.amount, winning_pool)?;
+                        
+                        if Self::_tracks_stats(round) {
+                            Self::_update_stats_win(env, user, &round.mode, position.amount, payout, None);
+                        }
+                    } else if Self::_tracks_stats(round) {
+                        Self::_update_stats_loss(env, user, &round.mode, position.amount, 0, None);
+                    }
+                }
+            }
+        }
+        Self::_credit_treasury(env, fee - referral_rewards)?;
+        
+        Ok(())
+    }
+
+    /// Losing pool after the protocol fee, plus the round's jackpot
+    fn _distributable_pool(env: &Env, round: &Round, losing_pool: i128) -> Result<i128, ContractError> {
+        let fee = Self::_calculate_fee(env, round, losing_pool)?;
+        losing_pool
+            .checked_sub(fee)
+            .and_then(|pool| pool.checked_add(round.jackpot))
+            .ok_or(ContractError::Overflow)
+    }
+
+    /// Payout for a winning stake: stake + stake * distributable / winning_pool
+    fn _winner_payout(stake: i128, winning_pool: i128, distributable: i128) -> Result<i128, ContractError> {
+        let share_numerator = stake
+            .checked_mul(distributable)
+            .ok_or(ContractError::Overflow)?;
+        let share = share_numerator / winning_pool;
+        stake
+            .checked_add(share)
+            .ok_or(ContractError::Overflow)
+    }
+
+    /// Payout per unit staked in basis points, 0 for an empty stake
+    fn _multiplier_bps(payout: i128, stake: i128) -> Result<i128, ContractError> {
+        if stake == 0 {
+            return Ok(0);
+        }
+        let scaled = payout
+            .checked_mul(10_000)
+            .ok_or(ContractError::Overflow)?;
+        Ok(scaled / stake)
+    }
+
+    /// Calculates the protocol fee on an amount using the configured basis points
+    /// Tournament rounds are fee-free: their chips cannot be paid into the treasury
+    fn _calculate_fee(env: &Env, round: &Round, amount: i128) -> Result<i128, ContractError> {
+        let fee_bps: u32 = env.storage().persistent().get(&DataKey::FeeBps).unwrap_or(0);
+        if fee_bps == 0 || amount <= 0 || round.tournament_id.is_some() {
+            return Ok(0);
+        }
+
+        let fee = amount
+            .checked_mul(fee_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / 10_000;
+
+        Ok(fee)
+    }
+
+    /// Adds collected fees to the treasury
+    fn _credit_treasury(env: &Env, amount: i128) -> Result<(), ContractError> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let treasury: i128 = env.storage().persistent().get(&DataKey::Treasury).unwrap_or(0);
+        let new_treasury = treasury
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Treasury, &new_treasury);
+
+        Ok(())
+    }
+
+    /// Records a winning settlement: payout is everything credited for the round
+    pub(crate) fn _update_stats_win(
+        env: &Env,
+        user: Address,
+        mode: &RoundMode,
+        stake: i128,
+        payout: i128,
+        error: Option<u128>,
+    ) {
+        Self::_record_stats(env, &user, |stats| {
+            Self::_settle_stats(stats, stake, payout, error);
+
+            stats.total_wins += 1;
+            stats.current_streak += 1;
+            stats.total_won = stats.total_won.saturating_add(payout);
+            stats.biggest_win = stats.biggest_win.max(payout.saturating_sub(stake));
+            match mode {
+                RoundMode::UpDown => stats.updown_wins += 1,
+                RoundMode::Precision => stats.precision_wins += 1,
+                RoundMode::Buckets => stats.buckets_wins += 1,
+            }
+
+            if stats.current_streak > stats.best_streak {
+                stats.best_streak = stats.current_streak;
+            }
+        });
+
+        if error == Some(0) {
+            Self::_award_achievement(env, &user, AchievementKind::ExactHit);
+        }
+    }
+
+    /// Records a losing settlement; payout covers partial refunds some Precision policies pay losers
+    pub(crate) fn _update_stats_loss(
+        env: &Env,
+        user: Address,
+        mode: &RoundMode,
+        stake: i128,
+        payout: i128,
+        error: Option<u128>,
+    ) {
+        Self::_record_stats(env, &user, |stats| {
+            Self::_settle_stats(stats, stake, payout, error);
+
+            stats.total_losses += 1;
+            stats.current_streak = 0;
+            match mode {
+                RoundMode::UpDown => stats.updown_losses += 1,
+                RoundMode::Precision => stats.precision_losses += 1,
+                RoundMode::Buckets => stats.buckets_losses += 1,
+            }
+        });
+    }
+
+    /// Records a refunded or voided round; wins, losses, streaks and P&L are untouched
+    pub(crate) fn _update_stats_refund(env: &Env, user: Address) {
+        Self::_record_stats(env, &user, |stats| {
+            stats.rounds_played += 1;
+            stats.refunds += 1;
+        });
+    }
+
+    /// Applies an update to the user's lifetime stats and, during a season, their season stats,
+    /// then re-ranks them on the matching leaderboards
+    fn _record_stats(env: &Env, user: &Address, apply: impl Fn(&mut UserStats)) {
+        let mut stats = Self::_load_stats(env, user);
+        apply(&mut stats);
+        Self::_store_stats(env, user, &stats);
+        Self::_update_leaderboards(env, None, user, &stats);
+        Self::_award_achievements(env, user, &stats);
+
+        if let Some(season_id) = env.storage().persistent().get::<_, u32>(&DataKey::Season(SeasonKey::Active)) {
+            let key = DataKey::Season(SeasonKey::Stats(season_id, user.clone()));
+            let mut season_stats = match env.storage().persistent().get(&key) {
+                Some(season_stats) => season_stats,
+                None => {
+                    let participants_key = DataKey::Season(SeasonKey::Participants(season_id));
+                    let mut participants: Vec<Address> = env.storage()
+                        .persistent()
+                        .get(&participants_key)
+                        .unwrap_or(Vec::new(env));
+                    participants.push_back(user.clone());
+                    env.storage().persistent().set(&participants_key, &participants);
+                    Self::_empty_stats()
+                },
+            };
+            apply(&mut season_stats);
+            env.storage().persistent().set(&key, &season_stats);
+            Self::_update_leaderboards(env, Some(season_id), user, &season_stats);
+        }
+    }
+
+    /// Tournament rounds are played with chips and leave stats and achievements untouched
+    fn _tracks_stats(round: &Round) -> bool {
+        round.tournament_id.is_none()
+    }
+
+    /// Awards every milestone the user's lifetime stats have reached
+    fn _award_achievements(env: &Env, user: &Address, stats: &UserStats) {
+        if stats.total_wins >= 1 {
+            Self::_award_achievement(env, user, AchievementKind::FirstWin);
+        }
+        if stats.best_streak >= 5 {
+            Self::_award_achievement(env, user, AchievementKind::Streak5);
+        }
+        if stats.best_streak >= 10 {
+            Self::_award_achievement(env, user, AchievementKind::Streak10);
+        }
+        if stats.rounds_played >= 100 {
+            Self::_award_achievement(env, user, AchievementKind::Rounds100);
+        }
+    }
+
+    /// Records an achievement the first time the user earns it
+    fn _award_achievement(env: &Env, user: &Address, kind: AchievementKind) {
+        let key = DataKey::Achievements(user.clone());
+        let mut achievements: Vec<Achievement> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        if achievements.iter().any(|achievement| achievement.kind == kind) {
+            return;
+        }
+
+        achievements.push_back(Achievement {
+            kind: kind.clone(),
+            earned_ledger: env.ledger().sequence(),
+        });
+        env.storage().persistent().set(&key, &achievements);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("badge"), symbol_short!("earned")),
+            (user.clone(), kind),
+        );
+    }
+
+    /// Re-ranks a user on every lifetime or season leaderboard after their stats change
+    fn _update_leaderboards(env: &Env, season_id: Option<u32>, user: &Address, stats: &UserStats) {
+        for kind in [LeaderboardKind::NetProfit, LeaderboardKind::BestStreak, LeaderboardKind::PrecisionAccuracy] {
+            let score = Self::_leaderboard_score(stats, &kind);
+            Self::_rank_on_leaderboard(env, season_id, kind, user, score);
+        }
+    }
+
+    /// A user's score on a leaderboard, or None while they do not qualify for it
+    fn _leaderboard_score(stats: &UserStats, kind: &LeaderboardKind) -> Option<i128> {
+        match kind {
+            LeaderboardKind::NetProfit => Some(stats.net_pnl),
+            LeaderboardKind::BestStreak => Some(stats.best_streak as i128).filter(|streak| *streak > 0),
+            LeaderboardKind::PrecisionAccuracy => {
+                let precision_rounds = stats.precision_wins + stats.precision_losses;
+                (precision_rounds >= MIN_ACCURACY_ROUNDS)
+                    .then(|| i128::try_from(stats.precision_avg_error).unwrap_or(i128::MAX))
+            },
+        }
+    }
+
+    fn _leaderboard_key(season_id: Option<u32>, kind: LeaderboardKind) -> DataKey {
+        match season_id {
+            Some(season_id) => DataKey::Season(SeasonKey::Leaderboard(season_id, kind)),
+            None => DataKey::Leaderboard(kind),
+        }
+    }
+
+    /// Moves a user to their place among a leaderboard's candidates, or removes them when `score` is None
+    /// Keeping LEADERBOARD_CANDIDATES entries lets the next candidate move up into the top
+    /// LEADERBOARD_SIZE when a listed user's score falls
+    fn _rank_on_leaderboard(
+        env: &Env,
+        season_id: Option<u32>,
+        kind: LeaderboardKind,
+        user: &Address,
+        score: Option<i128>,
+    ) {
+        let key = Self::_leaderboard_key(season_id, kind.clone());
+        let mut board: Vec<LeaderboardEntry> = env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+
+        if let Some(index) = board.iter().position(|entry| entry.user == *user) {
+            board.remove(index as u32);
+        }
+
+        if let Some(score) = score {
+            Self::_insert_ranked(&mut board, &kind, LeaderboardEntry { user: user.clone(), score });
+        }
+
+        env.storage().persistent().set(&key, &board);
+    }
+
+    /// Inserts an entry below every entry that ranks at or above it, keeping LEADERBOARD_CANDIDATES entries
+    fn _insert_ranked(board: &mut Vec<LeaderboardEntry>, kind: &LeaderboardKind, entry: LeaderboardEntry) {
+        // Lower average error ranks higher; earlier entries win ties
+        let ranks_above = |other: i128| match kind {
+            LeaderboardKind::PrecisionAccuracy => other <= entry.score,
+            _ => other >= entry.score,
+        };
+        let mut index = 0;
+        while board.get(index).is_some_and(|other| ranks_above(other.score)) {
+            index += 1;
+        }
+        if index < LEADERBOARD_CANDIDATES {
+            board.insert(index, entry);
+        }
+        if board.len() > LEADERBOARD_CANDIDATES {
+            board.pop_back();
+        }
+    }
+
+    /// Volume, P&L and accuracy shared by wins and losses
+    fn _settle_stats(stats: &mut UserStats, stake: i128, payout: i128, error: Option<u128>) {
+        stats.rounds_played += 1;
+        stats.total_staked = stats.total_staked.saturating_add(stake);
+        stats.net_pnl = stats.net_pnl.saturating_add(payout.saturating_sub(stake));
+        if stats.total_staked > 0 {
+            stats.roi_bps = stats.net_pnl.saturating_mul(10_000) / stats.total_staked;
+        }
+
+        if let Some(error) = error {
+            let settled = (stats.precision_wins + stats.precision_losses + 1) as u128;
+            stats.precision_total_error = stats.precision_total_error.saturating_add(error);
+            stats.precision_avg_error = stats.precision_total_error / settled;
+        }
+    }
+
+    /// Loads a user's stats, converting entries stored before volume tracking
+    /// Migrated entries keep wins, losses and streaks; volume starts from zero
+    fn _load_stats(env: &Env, user: &Address) -> UserStats {
+        if let Some(stats) = env.storage().persistent().get(&DataKey::UserStatsV2(user.clone())) {
+            return stats;
+        }
+
+        let mut stats = Self::_empty_stats();
+        if let Some(legacy) = env.storage().persistent().get::<_, LegacyUserStats>(&DataKey::UserStats(user.clone())) {
+            stats.total_wins = legacy.total_wins;
+            stats.total_losses = legacy.total_losses;
+            stats.current_streak = legacy.current_streak;
+            stats.best_streak = legacy.best_streak;
+            stats.rounds_played = legacy.total_wins + legacy.total_losses;
+        }
+        stats
+    }
+
+    fn _empty_stats() -> UserStats {
+        UserStats {
+            total_wins: 0,
+            total_losses: 0,
+            current_streak: 0,
+            best_streak: 0,
+            rounds_played: 0,
+            refunds: 0,
+            total_staked: 0,
+            total_won: 0,
+            net_pnl: 0,
+            roi_bps: 0,
+            biggest_win: 0,
+            updown_wins: 0,
+            updown_losses: 0,
+            precision_wins: 0,
+            precision_losses: 0,
+            buckets_wins: 0,
+            buckets_losses: 0,
+            precision_total_error: 0,
+            precision_avg_error: 0,
+        }
+    }
+
+    /// Stores a user's stats, dropping any legacy entry they were migrated from
+    fn _store_stats(env: &Env, user: &Address, stats: &UserStats) {
+        env.storage().persistent().set(&DataKey::UserStatsV2(user.clone()), stats);
+        env.storage().persistent().remove(&DataKey::UserStats(user.clone()));
+    }
+    
+    /// Creates a syndicate captained by the caller
+    /// Returns the new syndicate's id
+    pub fn create_syndicate(env: Env, captain: Address) -> Result<u32, ContractError> {
+        captain.require_auth();
+
+        let last_id: u32 = env.storage()
+            .persistent()
+            .get(&DataKey::Syndicate(SyndicateKey::Counter))
+            .unwrap_or(0);
+        let syndicate_id = last_id
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Syndicate(SyndicateKey::Counter), &syndicate_id);
+
+        // The pool bets from an address derived from this contract, which no key can sign for
+        let mut salt = [0u8; 32];
+        salt[..9].copy_from_slice(b"syndicate");
+        salt[28..].copy_from_slice(&syndicate_id.to_be_bytes());
+        let account = env.deployer()
+            .with_current_contract(BytesN::from_array(&env, &salt))
+            .deployed_address();
+
+        let syndicate = Syndicate {
+            syndicate_id,
+            captain: captain.clone(),
+            account,
+            members: Vec::new(&env),
+            total_shares: 0,
+            open_round: None,
+        };
+        env.storage().persistent().set(&DataKey::Syndicate(SyndicateKey::Info(syndicate_id)), &syndicate);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("syndicate"), symbol_short!("created")),
+            (syndicate_id, captain),
+        );
+
+        Ok(syndicate_id)
+    }
+
+    /// Moves vXLM from the user's balance into the syndicate pool in exchange for shares
+    /// Shares are priced at the pool's current value, so earlier members keep their gains.
+    /// Returns the shares issued
+    pub fn deposit_syndicate(env: Env, user: Address, syndicate_id: u32, amount: i128) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        let mut syndicate = Self::_syndicate(&env, syndicate_id)?;
+        if Self::_is_syndicate_locked(&env, &syndicate) {
+            return Err(ContractError::SyndicateLocked);
+        }
+        if !syndicate.members.contains(&user) && syndicate.members.len() >= MAX_SYNDICATE_MEMBERS {
+            return Err(ContractError::RegistrationClosed);
+        }
+
+        let user_balance = Self::balance(env.clone(), user.clone());
+        if user_balance < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        let pool = Self::_settle_syndicate(&env, &syndicate)?;
+
+        // A pool that lost everything starts over: its old shares are worthless
+        if pool == 0 && syndicate.total_shares > 0 {
+            for member in syndicate.members.iter() {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::Syndicate(SyndicateKey::Shares(syndicate_id, member)));
+            }
+            syndicate.members = Vec::new(&env);
+            syndicate.total_shares = 0;
+        }
+
+        let shares = if syndicate.total_shares == 0 {
+            amount
+        } else {
+            amount
+                .checked_mul(syndicate.total_shares)
+                .ok_or(ContractError::Overflow)?
+                / pool
+        };
+        if shares == 0 {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        let new_balance = user_balance
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(&env, user.clone(), new_balance)?;
+        let new_pool = pool
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(&env, syndicate.account.clone(), new_pool)?;
+
+        let held = Self::get_syndicate_shares(env.clone(), syndicate_id, user.clone());
+        if held == 0 {
+            syndicate.members.push_back(user.clone());
+        }
+        let new_held = held
+            .checked_add(shares)
+            .ok_or(ContractError::Overflow)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Syndicate(SyndicateKey::Shares(syndicate_id, user.clone())), &new_held);
+        syndicate.total_shares = syndicate.total_shares
+            .checked_add(shares)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Syndicate(SyndicateKey::Info(syndicate_id)), &syndicate);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("syndicate"), symbol_short!("deposit")),
+            (user, syndicate_id, amount, shares),
+        );
+
+        Ok(shares)
+    }
+
+    /// Redeems shares for their part of the syndicate pool, paid into the user's balance
+    /// Only allowed between rounds, while the pool has no open bet.
+    /// Returns the amount paid out
+    pub fn withdraw_syndicate(env: Env, user: Address, syndicate_id: u32, shares: i128) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        if shares <= 0 {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        let mut syndicate = Self::_syndicate(&env, syndicate_id)?;
+        if Self::_is_syndicate_locked(&env, &syndicate) {
+            return Err(ContractError::SyndicateLocked);
+        }
+
+        let held = Self::get_syndicate_shares(env.clone(), syndicate_id, user.clone());
+        if shares > held {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        let pool = Self::_settle_syndicate(&env, &syndicate)?;
+        let amount = pool
+            .checked_mul(shares)
+            .ok_or(ContractError::Overflow)?
+            / syndicate.total_shares;
+
+        let new_pool = pool
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(&env, syndicate.account.clone(), new_pool)?;
+        let new_balance = Self::balance(env.clone(), user.clone())
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(&env, user.clone(), new_balance)?;
+
+        let shares_key = DataKey::Syndicate(SyndicateKey::Shares(syndicate_id, user.clone()));
+        let remaining = held - shares;
+        if remaining == 0 {
+            env.storage().persistent().remove(&shares_key);
+            if let Some(index) = syndicate.members.first_index_of(user.clone()) {
+                syndicate.members.remove(index);
+            }
+        } else {
+            env.storage().persistent().set(&shares_key, &remaining);
+        }
+        syndicate.total_shares = syndicate.total_shares
+            .checked_sub(shares)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Syndicate(SyndicateKey::Info(syndicate_id)), &syndicate);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("syndicate"), symbol_short!("withdraw")),
+            (user, syndicate_id, shares, amount),
+        );
+
+        Ok(amount)
+    }
+
+    /// Bets from the syndicate pool on the active Up/Down round (captain only)
+    /// Winnings return to the pool, so every member gains in proportion to their shares
+    pub fn syndicate_bet(env: Env, syndicate_id: u32, amount: i128, side: BetSide) -> Result<(), ContractError> {
+        let mut syndicate = Self::_syndicate(&env, syndicate_id)?;
+
+        syndicate.captain.require_auth();
+
+        Self::_settle_syndicate(&env, &syndicate)?;
+        Self::_place_bet(&env, &syndicate.account, amount, side.clone())?;
+
+        let round_id = Self::get_active_round(env.clone())
+            .map(|round| round.round_id)
+            .ok_or(ContractError::NoActiveRound)?;
+        syndicate.open_round = Some(round_id);
+        env.storage().persistent().set(&DataKey::Syndicate(SyndicateKey::Info(syndicate_id)), &syndicate);
+
+        let betting_key = DataKey::Syndicate(SyndicateKey::Betting);
+        let mut betting: Vec<u32> = env.storage().persistent().get(&betting_key).unwrap_or(Vec::new(&env));
+        if !betting.contains(syndicate_id) {
+            betting.push_back(syndicate_id);
+            env.storage().persistent().set(&betting_key, &betting);
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("syndicate"), symbol_short!("bet")),
+            (syndicate_id, round_id, amount, side),
+        );
+
+        Ok(())
+    }
+
+    /// Claims a syndicate's winnings from resolved rounds into its pool (anyone may call)
+    /// Returns the pool's balance
+    pub fn settle_syndicate(env: Env, syndicate_id: u32) -> Result<i128, ContractError> {
+        let syndicate = Self::_syndicate(&env, syndicate_id)?;
+        let pool = Self::_settle_syndicate(&env, &syndicate)?;
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("syndicate"), symbol_short!("settled")),
+            (syndicate_id, pool),
+        );
+
+        Ok(pool)
+    }
+
+    /// Returns a syndicate's captain, members and shares
+    pub fn get_syndicate(env: Env, syndicate_id: u32) -> Option<Syndicate> {
+        env.storage().persistent().get(&DataKey::Syndicate(SyndicateKey::Info(syndicate_id)))
+    }
+
+    /// Returns the shares a member holds in a syndicate
+    pub fn get_syndicate_shares(env: Env, syndicate_id: u32, user: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Syndicate(SyndicateKey::Shares(syndicate_id, user)))
+            .unwrap_or(0)
+    }
+
+    /// Returns the syndicate pool's value: its balance plus unclaimed winnings, excluding open bets
+    pub fn get_syndicate_value(env: Env, syndicate_id: u32) -> Result<i128, ContractError> {
+        let syndicate = Self::_syndicate(&env, syndicate_id)?;
+        Self::balance(env.clone(), syndicate.account.clone())
+            .checked_add(Self::get_pending_winnings(env, syndicate.account))
+            .ok_or(ContractError::Overflow)
+    }
+
+    fn _syndicate(env: &Env, syndicate_id: u32) -> Result<Syndicate, ContractError> {
         env.storage()
             .persistent()
-            .get(&DataKey::UpDownPositions)
-            .unwrap_or(Map::new(&env))
+            .get(&DataKey::Syndicate(SyndicateKey::Info(syndicate_id)))
+            .ok_or(ContractError::NotFound)
     }
-    
-    /// Resolves the round with final price (oracle only)
-    /// Mode 0 (Up/Down): Winners split losers' pool proportionally; ties get refunds
-    /// Mode 1 (Precision/Legends): Closest guess wins full pot; ties split evenly
-    pub fn resolve_round(env: Env, final_price: u128) -> Result<(), ContractError> {
-        if final_price == 0 {
-            return Err(ContractError::InvalidPrice);
+
+    /// Returns true while the pool's last bet is on the active round
+    fn _is_syndicate_locked(env: &Env, syndicate: &Syndicate) -> bool {
+        match (Self::get_active_round(env.clone()), syndicate.open_round) {
+            (Some(round), Some(open_round)) => round.round_id == open_round,
+            _ => false,
         }
+    }
 
-        let oracle: Address = env.storage()
-            .persistent()
-            .get(&DataKey::Oracle)
-            .ok_or(ContractError::OracleNotSet)?;
+    /// Settles every syndicate that bet on the round being resolved, so no pool winnings are
+    /// left pending for a sweep to take
+    fn _settle_betting_syndicates(env: &Env) -> Result<(), ContractError> {
+        let betting_key = DataKey::Syndicate(SyndicateKey::Betting);
+        let betting: Vec<u32> = env.storage().persistent().get(&betting_key).unwrap_or(Vec::new(env));
+        for syndicate_id in betting.iter() {
+            Self::_settle_syndicate(env, &Self::_syndicate(env, syndicate_id)?)?;
+        }
+        env.storage().persistent().remove(&betting_key);
 
-        oracle.require_auth();
+        Ok(())
+    }
 
-        let round: Round = env.storage()
+    /// Claims the pool's winnings into its balance and returns that balance
+    /// Winnings whose claim deadline has lapsed stay pending until swept.
+    fn _settle_syndicate(env: &Env, syndicate: &Syndicate) -> Result<i128, ContractError> {
+        Self::_claim_open_rounds(env, &syndicate.account, u32::MAX)?;
+        Ok(Self::balance(env.clone(), syndicate.account.clone()))
+    }
+
+    /// Sets the faucet's refill floor, cooldown and daily emission caps (admin only)
+    pub fn set_faucet_config(env: Env, config: FaucetConfig) -> Result<(), ContractError> {
+        let admin: Address = env.storage()
             .persistent()
-            .get(&DataKey::ActiveRound)
-            .ok_or(ContractError::NoActiveRound)?;
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
 
-        // Verify round has reached end_ledger
-        let current_ledger = env.ledger().sequence();
-        if current_ledger < round.end_ledger {
-            return Err(ContractError::RoundNotEnded);
-        }
+        admin.require_auth();
 
-        // Branch based on round mode
-        match round.mode {
-            RoundMode::UpDown => {
-                Self::_resolve_updown_mode(&env, &round, final_price)?;
-            },
-            RoundMode::Precision => {
-                Self::_resolve_precision_mode(&env, final_price)?;
-            },
+        if config.floor <= 0 || config.user_daily_cap < 0 || config.global_daily_cap < 0 {
+            return Err(ContractError::InvalidParameters);
         }
 
-        // Clean up storage
-        env.storage().persistent().remove(&DataKey::ActiveRound);
-        env.storage().persistent().remove(&DataKey::Positions);
-        env.storage().persistent().remove(&DataKey::UpDownPositions);
-        env.storage().persistent().remove(&DataKey::PrecisionPositions);
+        env.storage().persistent().set(&DataKey::Token(TokenKey::FaucetConfig), &config);
 
-        // Emit resolution event
+        #[allow(deprecated)]
         env.events().publish(
-            (symbol_short!("round"), symbol_short!("resolved")),
-            final_price,
+            (symbol_short!("faucet"), symbol_short!("updated")),
+            (config.floor, config.cooldown_ledgers),
         );
 
         Ok(())
     }
 
-    /// Resolves Up/Down mode round
-    fn _resolve_updown_mode(env: &Env, round: &Round, final_price: u128) -> Result<(), ContractError> {
-        let positions: Map<Address, UserPosition> = env.storage()
+    /// Returns the faucet settings (1000 vXLM floor, one-day cooldown and per-address cap by default)
+    pub fn get_faucet_config(env: Env) -> FaucetConfig {
+        env.storage()
             .persistent()
-            .get(&DataKey::UpDownPositions)
-            .unwrap_or(Map::new(env));
+            .get(&DataKey::Token(TokenKey::FaucetConfig))
+            .unwrap_or(FaucetConfig {
+                floor: DEFAULT_FAUCET_FLOOR,
+                cooldown_ledgers: LEDGERS_PER_DAY,
+                user_daily_cap: DEFAULT_FAUCET_FLOOR,
+                global_daily_cap: 0,
+            })
+    }
+
+    /// Returns the user's last faucet refill, if any
+    pub fn get_faucet_claim(env: Env, user: Address) -> Option<FaucetClaim> {
+        env.storage().persistent().get(&DataKey::Token(TokenKey::FaucetClaim(user)))
+    }
 
-        let price_went_up = final_price > round.price_start;
-        let price_went_down = final_price < round.price_start;
-        let price_unchanged = final_price == round.price_start;
+    /// Returns all vXLM minted, less any burned, since supply tracking began
+    pub fn get_total_supply(env: Env) -> i128 {
+        env.storage().persistent().get(&DataKey::Token(TokenKey::TotalSupply)).unwrap_or(0)
+    }
+
+    /// Returns where the vXLM supply currently sits
+    /// Every part except `total_supply` adds up to `total_supply`. Like the total supply, the parts
+    /// only count activity since supply tracking began: balances held from before then are not
+    /// included, so spending them can leave `circulating` negative.
+    pub fn get_supply_breakdown(env: Env) -> Result<SupplyBreakdown, ContractError> {
+        let staked = match env.storage().persistent().get::<_, Round>(&DataKey::ActiveRound) {
+            Some(round) => Self::_round_stake(&env, &round)?,
+            None => 0,
+        };
+
+        Ok(SupplyBreakdown {
+            total_supply: Self::get_total_supply(env.clone()),
+            circulating: Self::_supply_counter(&env, TokenKey::Circulating),
+            staked,
+            pending: Self::_supply_counter(&env, TokenKey::Pending),
+            treasury: Self::get_treasury(env.clone()),
+            jackpot: Self::get_jackpot(env.clone()),
+            tournament_pools: Self::_supply_counter(&env, TokenKey::TournamentPools),
+            dust: Self::_supply_counter(&env, TokenKey::Dust),
+        })
+    }
+
+    fn _supply_counter(env: &Env, key: TokenKey) -> i128 {
+        env.storage().persistent().get(&DataKey::Token(key)).unwrap_or(0)
+    }
 
-        if price_unchanged {
-            Self::_record_refunds(env, positions)?;
-        } else if price_went_up {
-            Self::_record_winnings(env, positions, BetSide::Up, round.pool_up, round.pool_down)?;
-        } else if price_went_down {
-            Self::_record_winnings(env, positions, BetSide::Down, round.pool_down, round.pool_up)?;
+    /// Adds a (possibly negative) amount to one of the supply counters
+    fn _adjust_supply(env: &Env, key: TokenKey, delta: i128) -> Result<(), ContractError> {
+        if delta == 0 {
+            return Ok(());
         }
 
+        let value = Self::_supply_counter(env, key.clone())
+            .checked_add(delta)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Token(key), &value);
+
         Ok(())
     }
 
-    /// Resolves Precision/Legends mode round
-    /// Awards full pot to closest guess(es); ties split evenly
-    fn _resolve_precision_mode(env: &Env, final_price: u128) -> Result<(), ContractError> {
+    /// Returns the vXLM the round holds: its stakes, sealed stakes and jackpot
+    /// Tournament rounds are staked in chips, which are not part of the supply
+    fn _round_stake(env: &Env, round: &Round) -> Result<i128, ContractError> {
+        if round.tournament_id.is_some() {
+            return Ok(0);
+        }
+
+        let mut total = round.pool_up
+            .checked_add(round.pool_down)
+            .ok_or(ContractError::Overflow)?
+            .checked_add(round.jackpot)
+            .ok_or(ContractError::Overflow)?
+            .checked_add(Self::_sum_pools(&round.bucket_pools)?)
+            .ok_or(ContractError::Overflow)?
+            .checked_add(Self::_committed_stake(&Self::_precision_commits(env))?)
+            .ok_or(ContractError::Overflow)?;
+
         let predictions: Vec<PrecisionPrediction> = env.storage()
             .persistent()
             .get(&DataKey::PrecisionPositions)
             .unwrap_or(Vec::new(env));
+        for prediction in predictions.iter() {
+            total = total
+                .checked_add(prediction.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
 
-        // If no predictions, nothing to resolve
-        if predictions.is_empty() {
-            return Ok(());
+        Ok(total)
+    }
+
+    /// Returns the supply held outside rounds and tournaments, which resolution pays into
+    fn _settled_supply(env: &Env) -> Result<i128, ContractError> {
+        Self::_supply_counter(env, TokenKey::Circulating)
+            .checked_add(Self::_supply_counter(env, TokenKey::Pending))
+            .ok_or(ContractError::Overflow)?
+            .checked_add(Self::get_treasury(env.clone()))
+            .ok_or(ContractError::Overflow)?
+            .checked_add(Self::get_jackpot(env.clone()))
+            .ok_or(ContractError::Overflow)
+    }
+
+    /// Tops the user's balance up to the faucet floor once the cooldown since their last refill has passed
+    /// Pending winnings count toward the floor; the refill is cut to what the daily caps leave.
+    /// Returns the amount minted
+    pub fn claim_faucet(env: Env, user: Address) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        Self::_faucet_refill(&env, &user)
+    }
+
+    /// Mints the starting balance for new users through the faucet; kept for existing clients
+    /// Returns the balance unchanged once the user has one
+    pub fn mint_initial(env: Env, user: Address) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        if let Some(existing_balance) = env.storage().persistent().get(&DataKey::Balance(user.clone())) {
+            return Ok(existing_balance);
         }
 
-        // Find minimum difference and collect all winners
-        let mut min_diff: Option<u128> = None;
-        let mut winners: Vec<PrecisionPrediction> = Vec::new(env);
+        Self::_faucet_refill(&env, &user)?;
 
-        for i in 0..predictions.len() {
-            if let Some(pred) = predictions.get(i) {
-                // Calculate absolute difference using checked arithmetic
-                let diff = if pred.predicted_price >= final_price {
-                    pred.predicted_price
-                        .checked_sub(final_price)
-                        .ok_or(ContractError::Overflow)?
-                } else {
-                    final_price
-                        .checked_sub(pred.predicted_price)
-                        .ok_or(ContractError::Overflow)?
-                };
+        Ok(Self::balance(env, user))
+    }
 
-                match min_diff {
-                    None => {
-                        // First prediction
-                        min_diff = Some(diff);
-                        winners.push_back(pred.clone());
-                    },
-                    Some(current_min) => {
-                        if diff < current_min {
-                            // New winner found, clear previous winners
-                            min_diff = Some(diff);
-                            winners = Vec::new(env);
-                            winners.push_back(pred.clone());
-                        } else if diff == current_min {
-                            // Tie - add to winners
-                            winners.push_back(pred.clone());
-                        }
-                    },
-                }
-            }
+    fn _faucet_refill(env: &Env, user: &Address) -> Result<i128, ContractError> {
+        let config = Self::get_faucet_config(env.clone());
+
+        let balance = Self::balance(env.clone(), user.clone());
+        let holdings = balance
+            .checked_add(Self::get_pending_winnings(env.clone(), user.clone()))
+            .ok_or(ContractError::Overflow)?;
+        if holdings >= config.floor {
+            return Err(ContractError::FaucetLimitReached);
         }
 
-        // Calculate total pot
-        let mut total_pot: i128 = 0;
-        for i in 0..predictions.len() {
-            if let Some(pred) = predictions.get(i) {
-                total_pot = total_pot
-                    .checked_add(pred.amount)
-                    .ok_or(ContractError::Overflow)?;
+        let now = env.ledger().sequence();
+        let day = now / LEDGERS_PER_DAY;
+
+        let last_claim = Self::get_faucet_claim(env.clone(), user.clone());
+        let mut user_today: i128 = 0;
+        if let Some(claim) = last_claim {
+            if now < claim.last_ledger.saturating_add(config.cooldown_ledgers) {
+                return Err(ContractError::CooldownActive);
+            }
+            if claim.day == day {
+                user_today = claim.claimed_today;
             }
         }
 
-        // Distribute winnings to winner(s)
-        if !winners.is_empty() && total_pot > 0 {
-            let winner_count = winners.len() as i128;
-            let payout_per_winner = total_pot / winner_count;
+        let mut emission: FaucetEmission = env.storage()
+            .persistent()
+            .get(&DataKey::Token(TokenKey::FaucetEmission))
+            .unwrap_or(FaucetEmission { day, emitted: 0 });
+        if emission.day != day {
+            emission = FaucetEmission { day, emitted: 0 };
+        }
 
-            for i in 0..winners.len() {
-                if let Some(winner) = winners.get(i) {
-                    let key = DataKey::PendingWinnings(winner.user.clone());
-                    let existing_pending: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-                    let new_pending = existing_pending
-                        .checked_add(payout_per_winner)
-                        .ok_or(ContractError::Overflow)?;
-                    env.storage().persistent().set(&key, &new_pending);
+        let mut amount = config.floor - holdings;
+        if config.user_daily_cap > 0 {
+            amount = amount.min(config.user_daily_cap - user_today);
+        }
+        if config.global_daily_cap > 0 {
+            amount = amount.min(config.global_daily_cap - emission.emitted);
+        }
+        if amount <= 0 {
+            return Err(ContractError::FaucetLimitReached);
+        }
 
-                    Self::_update_stats_win(env, winner.user.clone());
-                }
-            }
+        let new_balance = balance
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(env, user.clone(), new_balance)?;
 
-            // Update stats for losers
-            for i in 0..predictions.len() {
-                if let Some(pred) = predictions.get(i) {
-                    let is_winner = winners.iter().any(|w| w.user == pred.user);
-                    if !is_winner {
-                        Self::_update_stats_loss(env, pred.user.clone());
-                    }
-                }
-            }
-        }
+        let claim = FaucetClaim {
+            last_ledger: now,
+            day,
+            claimed_today: user_today
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?,
+        };
+        env.storage().persistent().set(&DataKey::Token(TokenKey::FaucetClaim(user.clone())), &claim);
 
-        Ok(())
+        emission.emitted = emission.emitted
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DataKey::Token(TokenKey::FaucetEmission), &emission);
+
+        Self::_adjust_supply(env, TokenKey::TotalSupply, amount)?;
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("faucet"), symbol_short!("refill")),
+            (user.clone(), amount),
+        );
+
+        Ok(amount)
     }
 
-    /// Claims pending winnings and adds to balance
-    pub fn claim_winnings(env: Env, user: Address) -> i128 {
-        user.require_auth();
-        
-        let key = DataKey::PendingWinnings(user.clone());
-        let pending: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-        
-        if pending == 0 {
-            return 0;
+    /// Mints vXLM into a user's balance, e.g. for promotions or incident compensation (admin only)
+    /// Returns the user's new balance
+    pub fn mint(env: Env, to: Address, amount: i128) -> Result<i128, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidParameters);
         }
-        
-        let current_balance = Self::balance(env.clone(), user.clone());
-        let new_balance = current_balance + pending;
-        Self::_set_balance(&env, user.clone(), new_balance);
-        
-        env.storage().persistent().remove(&key);
-        
-        pending
+
+        Self::_mint(&env, &to, amount)
     }
-    
-    /// Records refunds when price unchanged
-    fn _record_refunds(env: &Env, positions: Map<Address, UserPosition>) -> Result<(), ContractError> {
-        let keys: Vec<Address> = positions.keys();
-        
-        for i in 0..keys.len() {
-            if let Some(user) = keys.get(i) {
-                if let Some(position) = positions.get(user.clone()) {
-                    let key = DataKey::PendingWinnings(user.clone());
-                    let existing_pending: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-                    let new_pending = existing_pending
-                        .checked_add(position.amount)
-                        .ok_or(ContractError::Overflow)?;
-                    env.storage().persistent().set(&key, &new_pending);
-                }
-            }
+
+    /// Burns vXLM from a user's balance; staked and pending amounts are untouched (admin only)
+    /// Returns the user's new balance
+    pub fn burn(env: Env, from: Address, amount: i128) -> Result<i128, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidParameters);
         }
-        
-        Ok(())
+
+        let balance = Self::balance(env.clone(), from.clone());
+        if balance < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+        let new_balance = balance
+            .checked_sub(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(&env, from.clone(), new_balance)?;
+        Self::_adjust_supply(&env, TokenKey::TotalSupply, -amount)?;
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("token"), symbol_short!("burn")),
+            (from, amount),
+        );
+
+        Ok(new_balance)
     }
-    
-    /// Records winnings for winning side
-    /// Formula: payout = bet + (bet / winning_pool) * losing_pool
-    fn _record_winnings(
-        env: &Env,
-        positions: Map<Address, UserPosition>,
-        winning_side: BetSide,
-        winning_pool: i128,
-        losing_pool: i128,
-    ) -> Result<(), ContractError> {
-        if winning_pool == 0 {
-            return Ok(());
+
+    /// Mints to several users at once (admin only)
+    /// Every amount must be positive; nothing is minted if any entry is invalid.
+    /// Returns the total minted
+    pub fn airdrop(env: Env, recipients: Vec<(Address, i128)>) -> Result<i128, ContractError> {
+        let admin: Address = env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::AdminNotSet)?;
+
+        admin.require_auth();
+
+        if recipients.is_empty() || recipients.len() > MAX_AIRDROP_RECIPIENTS {
+            return Err(ContractError::InvalidParameters);
         }
-        
-        let keys: Vec<Address> = positions.keys();
-        
-        for i in 0..keys.len() {
-            if let Some(user) = keys.get(i) {
-                if let Some(position) = positions.get(user.clone()) {
-                    if position.side == winning_side {
-                        let share_numerator = position.amount
-                            .checked_mul(losing_pool)
-                            .ok_or(ContractError::Overflow)?;
-                        let share = share_numerator / winning_pool;
-                        let payout = position.amount
-                            .checked_add(share)
-                            .ok_or(ContractError::Overflow)?;
-                        
-                        let key = DataKey::PendingWinnings(user.clone());
-                        let existing_pending: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-                        let new_pending = existing_pending
-                            .checked_add(payout)
-                            .ok_or(ContractError::Overflow)?;
-                        env.storage().persistent().set(&key, &new_pending);
-                        
-                        Self::_update_stats_win(env, user);
-                    } else {
-                        Self::_update_stats_loss(env, user);
-                    }
-                }
+
+        let mut total: i128 = 0;
+        for (_, amount) in recipients.iter() {
+            if amount <= 0 {
+                return Err(ContractError::InvalidParameters);
             }
+            total = total
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?;
         }
-        
-        Ok(())
-    }
-    
-    pub(crate) fn _update_stats_win(env: &Env, user: Address) {
-        let key = DataKey::UserStats(user);
-        let mut stats: UserStats = env.storage().persistent().get(&key).unwrap_or(UserStats {
-            total_wins: 0,
-            total_losses: 0,
-            current_streak: 0,
-            best_streak: 0,
-        });
-        
-        stats.total_wins += 1;
-        stats.current_streak += 1;
-        
-        if stats.current_streak > stats.best_streak {
-            stats.best_streak = stats.current_streak;
+
+        for (to, amount) in recipients.iter() {
+            Self::_mint(&env, &to, amount)?;
         }
-        
-        env.storage().persistent().set(&key, &stats);
-    }
-    
-    pub(crate) fn _update_stats_loss(env: &Env, user: Address) {
-        let key = DataKey::UserStats(user);
-        let mut stats: UserStats = env.storage().persistent().get(&key).unwrap_or(UserStats {
-            total_wins: 0,
-            total_losses: 0,
-            current_streak: 0,
-            best_streak: 0,
-        });
-        
-        stats.total_losses += 1;
-        stats.current_streak = 0;
-        
-        env.storage().persistent().set(&key, &stats);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("token"), symbol_short!("airdrop")),
+            (recipients.len(), total),
+        );
+
+        Ok(total)
     }
-    
-    /// Mints 1000 vXLM for new users (one-time only)
-    pub fn mint_initial(env: Env, user: Address) -> i128 {
-        user.require_auth();
-        
-        let key = DataKey::Balance(user.clone());
-        
-        if let Some(existing_balance) = env.storage().persistent().get(&key) {
-            return existing_balance;
-        }
-        
-        let initial_amount: i128 = 1000_0000000;
-        env.storage().persistent().set(&key, &initial_amount);
-        
-        initial_amount
+
+    fn _mint(env: &Env, to: &Address, amount: i128) -> Result<i128, ContractError> {
+        let new_balance = Self::balance(env.clone(), to.clone())
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        Self::_set_balance(env, to.clone(), new_balance)?;
+        Self::_adjust_supply(env, TokenKey::TotalSupply, amount)?;
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("token"), symbol_short!("mint")),
+            (to.clone(), amount),
+        );
+
+        Ok(new_balance)
     }
     
     /// Returns user's vXLM balance
@@ -681,9 +4269,22 @@ impl VirtualTokenContract {
         env.storage().persistent().get(&key).unwrap_or(0)
     }
     
-    pub(crate) fn _set_balance(env: &Env, user: Address, amount: i128) {
+    /// Sets a user's balance, keeping the circulating supply in step
+    pub(crate) fn _set_balance(env: &Env, user: Address, amount: i128) -> Result<(), ContractError> {
         let key = DataKey::Balance(user);
+        let previous: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+
+        let circulating_key = DataKey::Token(TokenKey::Circulating);
+        let circulating: i128 = env.storage().persistent().get(&circulating_key).unwrap_or(0);
+        let new_circulating = circulating
+            .checked_sub(previous)
+            .and_then(|c| c.checked_add(amount))
+            .ok_or(ContractError::Overflow)?;
+
         env.storage().persistent().set(&key, &amount);
+        env.storage().persistent().set(&circulating_key, &new_circulating);
+
+        Ok(())
     }
 }
 
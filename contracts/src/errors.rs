@@ -3,6 +3,9 @@
 use soroban_sdk::contracterror;
 
 /// Contract error types
+///
+/// The contract spec allows at most 50 error cases, so new features reuse the general errors
+/// (`NotFound`, `AlreadyRegistered`, `InvalidParameters`, ...) where they fit
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -33,13 +36,81 @@ pub enum ContractError {
     InvalidPrice = 12,
     /// Invalid duration value
     InvalidDuration = 13,
-    /// Invalid round mode (must be 0 or 1)
+    /// Invalid round mode (must be 0, 1 or 2)
     InvalidMode = 14,
-    /// Wrong prediction type for current round mode
+    /// Wrong prediction type for current round mode; plaintext guesses while a reveal window is set
     WrongModeForPrediction = 15,
-    /// Round has not reached end_ledger yet
+    /// Round has not reached end_ledger yet, or an unresolved round still holds stakes
     RoundNotEnded = 16,
     /// Invalid price scale (must represent 4 decimal places)
     InvalidPriceScale = 17,
+    /// Fee must be between 0 and 10_000 basis points
+    InvalidFee = 18,
+    /// User has no unclaimed winnings for the requested round
+    NoWinningsForRound = 19,
+    /// Claim window for this round has lapsed
+    ClaimExpired = 20,
+    /// Claim window for this round is still open
+    ClaimWindowOpen = 21,
+    /// Round has no claim deadline to enforce
+    NoClaimDeadline = 22,
+    /// Early-exit penalty must be between 0 and 10_000 basis points
+    InvalidPenalty = 23,
+    /// Round id does not match the active round
+    RoundMismatch = 24,
+    /// User has no open bet in this round
+    NoPosition = 25,
+    /// Withdrawal exceeds the user's open stake
+    InsufficientStake = 26,
+    /// Stake is below the minimum bet for this mode
+    BetBelowMinimum = 27,
+    /// Stake exceeds the per-user maximum for this mode
+    BetAboveMaximum = 28,
+    /// Stake would push the round's pool over its cap
+    PoolCapExceeded = 29,
+    /// Bet limits must be non-negative and min_bet may not exceed max_bet_per_user
+    InvalidBetLimits = 30,
+    /// Bucket bounds must be 1 to 9 strictly ascending values
+    InvalidBuckets = 31,
+    /// Bucket index is out of range for this round
+    InvalidBucket = 32,
+    /// Precision payout policy parameters are out of range
+    InvalidPayoutPolicy = 33,
+    /// Sealed predictions can only be revealed after betting closes
+    RevealNotOpen = 34,
+    /// Reveal window for this round has closed
+    RevealWindowClosed = 35,
+    /// User has no sealed prediction in this round
+    NoCommitment = 36,
+    /// Revealed price and salt do not match the sealed prediction
+    CommitmentMismatch = 37,
+    /// A season is already running
+    SeasonActive = 38,
+    /// No season is running
+    NoActiveSeason = 39,
+    /// Prize pool must be non-negative and prize tiers 1 to 10 positive bps summing to 10000
+    InvalidPrizes = 40,
+    /// Tournament, syndicate or other record does not exist
+    NotFound = 41,
+    /// Registration is closed: the tournament started or is full, the syndicate is full,
+    /// or the user has already bet before naming a referrer
+    RegistrationClosed = 42,
+    /// User has already joined this tournament or registered a referrer
+    AlreadyRegistered = 43,
+    /// Tournament has played all its rounds or has finished
+    TournamentOver = 44,
+    /// Tournament still has rounds to play, or its open round must resolve before another round starts
+    TournamentNotOver = 45,
+    /// Parameters are out of range for this call, such as redeeming more syndicate shares than held,
+    /// or a tournament round has no players to play it
+    InvalidParameters = 46,
+    /// Users cannot refer themselves or anyone who referred them, directly or indirectly
+    InvalidReferrer = 47,
+    /// Faucet cooldown since the user's last refill has not elapsed
+    CooldownActive = 48,
+    /// Balance already reaches the faucet floor, or a daily faucet cap is used up
+    FaucetLimitReached = 49,
+    /// Syndicate has a bet on the active round; deposits and withdrawals wait until it resolves
+    SyndicateLocked = 50,
 }
 
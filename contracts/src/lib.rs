@@ -17,6 +17,14 @@ mod types;
 #[cfg(test)]
 mod tests;
 
-pub use contract::VirtualTokenContract;
+pub use contract::{VirtualTokenContract, VirtualTokenContractClient};
 pub use errors::ContractError;
-pub use types::{BetSide, DataKey, Round, UserPosition, UserStats};
+pub use types::{
+    Achievement, AchievementKind, BetLimits, BetQuote, BetSide, BucketPosition, ContractConfig, DataKey,
+    ExpiringClaim, FaucetClaim, FaucetConfig, FaucetEmission, LeaderboardEntry, LeaderboardKind,
+    LegacyUserStats, PenaltyDestination, PrecisionCommit, PrecisionPayout, PrecisionPrediction, PrecisionRank,
+    PrecisionTieRules, ReferralStats, RemainderPolicy, Round, RoundClaim, RoundMode, RoundOdds, RoundOutcome,
+    RoundResult, Scoring, ScoringKernel, Season, SeasonKey, StakeWeighting, SupplyBreakdown, SweepDestination,
+    Syndicate, SyndicateKey, TokenKey, Tolerance, Tournament, TournamentStanding, TournamentStatus,
+    UserPosition, UserStats,
+};
@@ -0,0 +1,180 @@
+//! Tests for achievement badges awarded from stats milestones.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::types::{Achievement, AchievementKind, BetSide, RoundMode};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+fn kinds(client: &VirtualTokenContractClient, user: &Address) -> soroban_sdk::Vec<AchievementKind> {
+    let mut kinds = soroban_sdk::Vec::new(&client.env);
+    for achievement in client.get_achievements(user).iter() {
+        kinds.push_back(achievement.kind);
+    }
+    kinds
+}
+
+#[test]
+fn test_first_win_badge() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    assert!(client.get_achievements(&alice).is_empty());
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    let ledger = env.ledger().sequence();
+    assert_eq!(
+        client.get_achievements(&alice),
+        vec![&env, Achievement { kind: AchievementKind::FirstWin, earned_ledger: ledger }]
+    );
+    assert!(client.get_achievements(&bob).is_empty());
+}
+
+#[test]
+fn test_streak_badges_awarded_once() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    env.as_contract(&client.address, || {
+        for _ in 0..12 {
+            VirtualTokenContract::_update_stats_win(&env, alice.clone(), &RoundMode::UpDown, 100, 200, None);
+        }
+    });
+
+    assert_eq!(
+        kinds(&client, &alice),
+        vec![&env, AchievementKind::FirstWin, AchievementKind::Streak5, AchievementKind::Streak10]
+    );
+
+    // Losing and winning again does not re-award anything
+    env.as_contract(&client.address, || {
+        VirtualTokenContract::_update_stats_loss(&env, alice.clone(), &RoundMode::UpDown, 100, 0, None);
+        VirtualTokenContract::_update_stats_win(&env, alice.clone(), &RoundMode::UpDown, 100, 200, None);
+    });
+    assert_eq!(client.get_achievements(&alice).len(), 3);
+}
+
+#[test]
+fn test_exact_hit_badge() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &Some(1));
+    client.place_precision_prediction(&alice, &100_0000000, &1_2345);
+    client.place_precision_prediction(&bob, &100_0000000, &1_3000);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_2345);
+
+    assert_eq!(
+        kinds(&client, &alice),
+        vec![&env, AchievementKind::FirstWin, AchievementKind::ExactHit]
+    );
+    assert!(client.get_achievements(&bob).is_empty());
+}
+
+#[test]
+fn test_rounds_played_badge_counts_refunds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    env.as_contract(&client.address, || {
+        for _ in 0..99 {
+            VirtualTokenContract::_update_stats_refund(&env, alice.clone());
+        }
+    });
+    assert!(client.get_achievements(&alice).is_empty());
+
+    env.as_contract(&client.address, || {
+        VirtualTokenContract::_update_stats_refund(&env, alice.clone());
+    });
+    assert_eq!(kinds(&client, &alice), vec![&env, AchievementKind::Rounds100]);
+}
+
+#[test]
+fn test_tournament_rounds_award_nothing() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_tournament(&0, &1_000, &1, &vec![&env, 10_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+
+    client.create_tournament_round(&1, &1_0000000, &Some(1));
+    client.place_precision_prediction(&alice, &500, &1_2345);
+    client.place_precision_prediction(&bob, &500, &1_3000);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_2345);
+
+    assert!(client.get_achievements(&alice).is_empty());
+}
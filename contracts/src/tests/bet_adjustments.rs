@@ -0,0 +1,225 @@
+//! Tests for increasing and withdrawing open bets during the bet window.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, PenaltyDestination, UserPosition};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env};
+
+#[test]
+fn test_increase_bet() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&user);
+    client.create_round(&1_0000000, &None);
+
+    client.place_bet(&user, &100_0000000, &BetSide::Down);
+    client.increase_bet(&user, &1, &50_0000000);
+
+    assert_eq!(
+        client.get_user_position(&user),
+        Some(UserPosition { amount: 150_0000000, side: BetSide::Down })
+    );
+    assert_eq!(client.get_active_round().unwrap().pool_down, 150_0000000);
+    assert_eq!(client.balance(&user), 850_0000000);
+}
+
+#[test]
+fn test_increase_bet_insufficient_balance() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&user);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&user, &600_0000000, &BetSide::Up);
+
+    let result = client.try_increase_bet(&user, &1, &500_0000000);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+}
+
+#[test]
+fn test_withdraw_bet_without_penalty() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&user);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&user, &100_0000000, &BetSide::Up);
+
+    // Partial withdrawal keeps the position open
+    assert_eq!(client.withdraw_bet(&user, &1, &40_0000000), 40_0000000);
+    assert_eq!(client.get_user_position(&user).unwrap().amount, 60_0000000);
+    assert_eq!(client.get_active_round().unwrap().pool_up, 60_0000000);
+
+    // Withdrawing the rest closes it, allowing a fresh bet on the other side
+    assert_eq!(client.withdraw_bet(&user, &1, &60_0000000), 60_0000000);
+    assert_eq!(client.get_user_position(&user), None);
+    assert_eq!(client.get_active_round().unwrap().pool_up, 0);
+    assert_eq!(client.balance(&user), 1000_0000000);
+
+    client.place_bet(&user, &10_0000000, &BetSide::Down);
+    assert_eq!(client.get_active_round().unwrap().pool_down, 10_0000000);
+}
+
+#[test]
+fn test_withdraw_penalty_to_pool() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_exit_penalty(&1_000, &PenaltyDestination::Pool);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.create_round(&1_0000000, &None);
+
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    // 10% of the withdrawn 50 stays in the round
+    assert_eq!(client.withdraw_bet(&bob, &1, &50_0000000), 45_0000000);
+    let round = client.get_active_round().unwrap();
+    assert_eq!(round.pool_down, 50_0000000);
+    assert_eq!(round.jackpot, 5_0000000);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+    });
+    client.resolve_round(&1_5000000);
+
+    // 100 stake + 50 from Bob + 5 penalty
+    assert_eq!(client.get_pending_winnings(&alice), 155_0000000);
+}
+
+#[test]
+fn test_withdraw_penalty_to_treasury() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_exit_penalty(&500, &PenaltyDestination::Treasury);
+    assert_eq!(client.get_exit_penalty_bps(), 500);
+    assert_eq!(client.get_exit_penalty_destination(), PenaltyDestination::Treasury);
+
+    client.mint_initial(&user);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&user, &100_0000000, &BetSide::Up);
+
+    assert_eq!(client.withdraw_bet(&user, &1, &100_0000000), 95_0000000);
+    assert_eq!(client.get_treasury(), 5_0000000);
+    assert_eq!(client.get_active_round().unwrap().jackpot, 0);
+    assert_eq!(client.balance(&user), 995_0000000);
+}
+
+#[test]
+fn test_set_exit_penalty_bounds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    assert_eq!(client.get_exit_penalty_bps(), 0);
+    assert_eq!(client.get_exit_penalty_destination(), PenaltyDestination::Pool);
+
+    let result = client.try_set_exit_penalty(&10_001, &PenaltyDestination::Pool);
+    assert_eq!(result, Err(Ok(ContractError::InvalidPenalty)));
+}
+
+#[test]
+fn test_adjustments_rejected() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+
+    assert_eq!(client.try_increase_bet(&alice, &2, &1), Err(Ok(ContractError::RoundMismatch)));
+    assert_eq!(client.try_withdraw_bet(&bob, &1, &1), Err(Ok(ContractError::NoPosition)));
+    assert_eq!(client.try_withdraw_bet(&alice, &1, &0), Err(Ok(ContractError::InvalidBetAmount)));
+    assert_eq!(
+        client.try_withdraw_bet(&alice, &1, &100_0000001),
+        Err(Ok(ContractError::InsufficientStake))
+    );
+
+    // Bet window closes at ledger 6
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 6;
+    });
+    assert_eq!(client.try_increase_bet(&alice, &1, &1), Err(Ok(ContractError::RoundEnded)));
+    assert_eq!(client.try_withdraw_bet(&alice, &1, &1), Err(Ok(ContractError::RoundEnded)));
+}
+
+#[test]
+fn test_adjustments_on_precision_round_fail() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&user);
+    client.create_round(&1_0000000, &Some(1));
+    client.place_precision_prediction(&user, &100_0000000, &2297);
+
+    let result = client.try_withdraw_bet(&user, &1, &10_0000000);
+    assert_eq!(result, Err(Ok(ContractError::WrongModeForPrediction)));
+}
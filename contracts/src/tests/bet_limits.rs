@@ -0,0 +1,177 @@
+//! Tests for per-mode stake limits and the config view.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetLimits, BetSide, PenaltyDestination};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_default_config() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    let no_limits = BetLimits { min_bet: 0, max_bet_per_user: 0, max_pool_size: 0 };
+    let config = client.get_config();
+    assert_eq!(config.bet_ledgers, 6);
+    assert_eq!(config.run_ledgers, 12);
+    assert_eq!(config.fee_bps, 0);
+    assert_eq!(config.exit_penalty_bps, 0);
+    assert_eq!(config.exit_penalty_destination, PenaltyDestination::Pool);
+    assert_eq!(config.claim_window, None);
+    assert_eq!(config.updown_limits, no_limits);
+    assert_eq!(config.precision_limits, no_limits);
+}
+
+#[test]
+fn test_set_bet_limits_per_mode() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_bet_limits(&0, &1_0000000, &100_0000000, &1000_0000000);
+    client.set_bet_limits(&1, &5_0000000, &0, &0);
+
+    let config = client.get_config();
+    assert_eq!(
+        config.updown_limits,
+        BetLimits { min_bet: 1_0000000, max_bet_per_user: 100_0000000, max_pool_size: 1000_0000000 }
+    );
+    assert_eq!(config.precision_limits, client.get_bet_limits(&1));
+    assert_eq!(config.precision_limits.min_bet, 5_0000000);
+}
+
+#[test]
+fn test_set_bet_limits_invalid() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    assert_eq!(client.try_set_bet_limits(&3, &0, &0, &0), Err(Ok(ContractError::InvalidMode)));
+    assert_eq!(client.try_set_bet_limits(&0, &-1, &0, &0), Err(Ok(ContractError::InvalidBetLimits)));
+    assert_eq!(client.try_set_bet_limits(&0, &10, &5, &0), Err(Ok(ContractError::InvalidBetLimits)));
+    assert_eq!(client.try_get_bet_limits(&7), Err(Ok(ContractError::InvalidMode)));
+}
+
+#[test]
+fn test_place_bet_enforces_limits() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_bet_limits(&0, &10_0000000, &100_0000000, &150_0000000);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.create_round(&1_0000000, &None);
+
+    assert_eq!(
+        client.try_place_bet(&alice, &9_0000000, &BetSide::Up),
+        Err(Ok(ContractError::BetBelowMinimum))
+    );
+    assert_eq!(
+        client.try_place_bet(&alice, &101_0000000, &BetSide::Up),
+        Err(Ok(ContractError::BetAboveMaximum))
+    );
+
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    assert_eq!(
+        client.try_place_bet(&bob, &60_0000000, &BetSide::Down),
+        Err(Ok(ContractError::PoolCapExceeded))
+    );
+    client.place_bet(&bob, &50_0000000, &BetSide::Down);
+
+    // Failed attempts leave balances untouched
+    assert_eq!(client.balance(&bob), 950_0000000);
+}
+
+#[test]
+fn test_bet_adjustments_enforce_limits() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_bet_limits(&0, &10_0000000, &100_0000000, &0);
+    client.mint_initial(&user);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&user, &50_0000000, &BetSide::Up);
+
+    assert_eq!(client.try_increase_bet(&user, &1, &51_0000000), Err(Ok(ContractError::BetAboveMaximum)));
+    assert_eq!(client.try_withdraw_bet(&user, &1, &45_0000000), Err(Ok(ContractError::BetBelowMinimum)));
+
+    // Full exits are always allowed
+    assert_eq!(client.withdraw_bet(&user, &1, &50_0000000), 50_0000000);
+}
+
+#[test]
+fn test_precision_prediction_enforces_limits() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_bet_limits(&1, &1_0000000, &80_0000000, &100_0000000);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.create_round(&1_0000000, &Some(1));
+
+    assert_eq!(
+        client.try_place_precision_prediction(&alice, &5000000, &2297),
+        Err(Ok(ContractError::BetBelowMinimum))
+    );
+    assert_eq!(
+        client.try_place_precision_prediction(&alice, &81_0000000, &2297),
+        Err(Ok(ContractError::BetAboveMaximum))
+    );
+
+    client.place_precision_prediction(&alice, &80_0000000, &2297);
+    assert_eq!(
+        client.try_place_precision_prediction(&bob, &30_0000000, &2300),
+        Err(Ok(ContractError::PoolCapExceeded))
+    );
+
+    // Up/Down limits do not apply to Precision rounds
+    client.set_bet_limits(&0, &50_0000000, &0, &0);
+    client.place_precision_prediction(&bob, &20_0000000, &2300);
+}
@@ -0,0 +1,323 @@
+//! Tests for Buckets mode (stakes on ranges of price change).
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, BucketPosition, RoundMode};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+#[test]
+fn test_create_buckets_round() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.create_round(&1_0000000, &Some(2));
+
+    let round = client.get_active_round().unwrap();
+    assert_eq!(round.mode, RoundMode::Buckets);
+    assert_eq!(round.bucket_bounds, vec![&env, -200, -50, 50, 200]);
+    assert_eq!(round.bucket_pools, vec![&env, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_place_bucket_bet() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &Some(2));
+
+    client.place_bucket_bet(&alice, &100_0000000, &3);
+    client.place_bucket_bet(&bob, &50_0000000, &3);
+
+    let round = client.get_active_round().unwrap();
+    assert_eq!(round.bucket_pools, vec![&env, 0, 0, 0, 150_0000000, 0]);
+    assert_eq!(
+        client.get_user_bucket_position(&alice),
+        Some(BucketPosition { amount: 100_0000000, bucket: 3 })
+    );
+    assert_eq!(client.get_bucket_positions().len(), 2);
+    assert_eq!(client.balance(&alice), 900_0000000);
+
+    assert_eq!(client.try_place_bucket_bet(&alice, &1, &0), Err(Ok(ContractError::AlreadyBet)));
+}
+
+#[test]
+fn test_place_bucket_bet_rejected() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    client.create_round(&1_0000000, &Some(2));
+
+    assert_eq!(client.try_place_bucket_bet(&alice, &10, &5), Err(Ok(ContractError::InvalidBucket)));
+    assert_eq!(client.try_place_bucket_bet(&alice, &0, &1), Err(Ok(ContractError::InvalidBetAmount)));
+    assert_eq!(
+        client.try_place_bet(&alice, &10, &BetSide::Up),
+        Err(Ok(ContractError::WrongModeForPrediction))
+    );
+    assert_eq!(
+        client.try_place_precision_prediction(&alice, &10, &2297),
+        Err(Ok(ContractError::WrongModeForPrediction))
+    );
+
+    client.create_round(&1_0000000, &None);
+    assert_eq!(
+        client.try_place_bucket_bet(&alice, &10, &1),
+        Err(Ok(ContractError::WrongModeForPrediction))
+    );
+}
+
+#[test]
+fn test_bucket_resolution_parimutuel() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&1_0000000, &Some(2));
+
+    client.place_bucket_bet(&alice, &100_0000000, &3);
+    client.place_bucket_bet(&bob, &100_0000000, &0);
+    client.place_bucket_bet(&charlie, &50_0000000, &2);
+
+    // +1% lands in the +0.5..+2% bucket
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_0100000);
+
+    assert_eq!(client.get_pending_winnings(&alice), 250_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 0);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+    assert_eq!(client.get_user_stats(&alice).total_wins, 1);
+    assert_eq!(client.get_user_stats(&bob).total_losses, 1);
+    assert_eq!(client.get_bucket_positions().len(), 0);
+}
+
+#[test]
+fn test_bucket_resolution_with_fee() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&1_0000000, &Some(2));
+    client.set_fee_bps(&1_000);
+
+    client.place_bucket_bet(&alice, &30_0000000, &1);
+    client.place_bucket_bet(&bob, &10_0000000, &1);
+    client.place_bucket_bet(&charlie, &100_0000000, &4);
+
+    // -1% lands in the -2..-0.5% bucket; 10% of Charlie's 100 goes to the treasury
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&9900000);
+
+    assert_eq!(client.get_treasury(), 10_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 97_5000000);
+    assert_eq!(client.get_pending_winnings(&bob), 32_5000000);
+}
+
+#[test]
+fn test_bucket_boundaries() {
+    // (final price, expected bucket) around the default -200/-50/50/200 bps bounds
+    let cases = [
+        (1_0200000u128, 4u32),  // exactly +2% belongs to the top bucket
+        (1_0199999, 3),
+        (9950000, 2),           // exactly -0.5% is flat
+        (9949999, 1),
+        (9799999, 0),           // just under -2% floors below the bound
+        (1_0000000, 2),
+    ];
+
+    for (final_price, expected) in cases {
+        let env = Env::default();
+        let contract_id = env.register(VirtualTokenContract, ());
+        let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let oracle = Address::generate(&env);
+        let alice = Address::generate(&env);
+
+        env.mock_all_auths();
+
+        client.initialize(&admin, &oracle);
+        client.mint_initial(&alice);
+
+        client.create_round(&1_0000000, &Some(2));
+
+        client.place_bucket_bet(&alice, &100_0000000, &expected);
+
+        let end_ledger = client.get_active_round().unwrap().end_ledger;
+        env.ledger().with_mut(|li| {
+            li.sequence_number = end_ledger;
+        });
+        client.resolve_round(&final_price);
+
+        assert_eq!(client.get_user_stats(&alice).total_wins, 1, "price {}", final_price);
+    }
+}
+
+#[test]
+fn test_empty_winning_bucket_refunds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &Some(2));
+
+    client.place_bucket_bet(&alice, &100_0000000, &0);
+    client.place_bucket_bet(&bob, &40_0000000, &4);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_0000000);
+
+    assert_eq!(client.get_pending_winnings(&alice), 100_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 40_0000000);
+    assert_eq!(client.get_user_stats(&alice).total_losses, 0);
+}
+
+#[test]
+fn test_set_bucket_bounds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    client.create_round(&1_0000000, &Some(2));
+
+    assert_eq!(client.try_set_bucket_bounds(&vec![&env]), Err(Ok(ContractError::InvalidBuckets)));
+    assert_eq!(client.try_set_bucket_bounds(&vec![&env, 10, 10]), Err(Ok(ContractError::InvalidBuckets)));
+    assert_eq!(
+        client.try_set_bucket_bounds(&vec![&env, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]),
+        Err(Ok(ContractError::InvalidBuckets))
+    );
+
+    // New bounds only apply to rounds created afterwards
+    client.set_bucket_bounds(&vec![&env, 0]);
+    assert_eq!(client.get_active_round().unwrap().bucket_pools.len(), 5);
+    assert_eq!(client.get_config().bucket_bounds, vec![&env, 0]);
+
+    client.create_round(&1_0000000, &Some(2));
+    let round = client.get_active_round().unwrap();
+    assert_eq!(round.bucket_bounds, vec![&env, 0]);
+    assert_eq!(round.bucket_pools, vec![&env, 0, 0]);
+    assert_eq!(client.try_place_bucket_bet(&alice, &10, &2), Err(Ok(ContractError::InvalidBucket)));
+}
+
+#[test]
+fn test_buckets_start_price_must_be_resolvable() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &Some(2));
+
+    let max_start = (i128::MAX / 10_000) as u128;
+    assert_eq!(
+        client.try_create_round(&(i128::MAX as u128 + 1), &Some(2)),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_create_round(&(max_start + 1), &Some(2)),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+
+    // The largest start price still resolves, here into the top bucket at +300%
+    client.create_round(&max_start, &Some(2));
+    client.place_bucket_bet(&alice, &100_0000000, &4);
+    client.place_bucket_bet(&bob, &100_0000000, &0);
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&(max_start * 4));
+    assert_eq!(client.get_pending_winnings(&alice), 200_0000000);
+}
@@ -0,0 +1,296 @@
+//! Tests for claim deadlines and sweeping unclaimed winnings.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, ExpiringClaim, SweepDestination};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+/// Plays one Up/Down round where `winner` bets UP against `loser` and the price rises
+fn play_winning_round(env: &Env, client: &VirtualTokenContractClient, winner: &Address, loser: &Address) {
+    client.create_round(&1_0000000, &None);
+    client.place_bet(winner, &10_0000000, &BetSide::Up);
+    client.place_bet(loser, &10_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+}
+
+fn advance_to(env: &Env, ledger: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number = ledger;
+    });
+}
+
+#[test]
+fn test_no_deadline_by_default() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_round(&env, &client, &alice, &bob);
+
+    assert_eq!(client.get_claim_window(), None);
+    assert_eq!(client.get_claim_deadline(&1), None);
+    assert!(client.get_expiring_claims(&alice).is_empty());
+
+    // Claims never lapse without a window
+    advance_to(&env, 1_000_000);
+    assert_eq!(client.claim_winnings(&alice), 20_0000000);
+
+    let result = client.try_sweep_unclaimed(&1, &SweepDestination::Treasury);
+    assert_eq!(result, Err(Ok(ContractError::NoClaimDeadline)));
+}
+
+#[test]
+fn test_expiring_claims_listed() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_claim_window(&100);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    // Resolved at ledger 12
+    play_winning_round(&env, &client, &alice, &bob);
+
+    assert_eq!(client.get_claim_deadline(&1), Some(112));
+    assert_eq!(client.get_expiring_rounds(), vec![&env, 1]);
+    assert_eq!(
+        client.get_expiring_claims(&alice),
+        vec![&env, ExpiringClaim { round_id: 1, amount: 20_0000000, deadline_ledger: 112 }]
+    );
+
+    // Claiming on the deadline ledger is still allowed
+    advance_to(&env, 112);
+    assert_eq!(client.claim_round(&alice, &1), 20_0000000);
+    assert!(client.get_expiring_claims(&alice).is_empty());
+}
+
+#[test]
+fn test_claim_after_deadline_fails() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_claim_window(&100);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_round(&env, &client, &alice, &bob);
+    advance_to(&env, 113);
+
+    let result = client.try_claim_round(&alice, &1);
+    assert_eq!(result, Err(Ok(ContractError::ClaimExpired)));
+
+    // Bulk claims skip lapsed rounds and leave them for the sweep
+    assert_eq!(client.claim_winnings(&alice), 0);
+    assert_eq!(client.claim_all(&alice, &10), 0);
+    assert_eq!(client.get_pending_winnings(&alice), 20_0000000);
+}
+
+#[test]
+fn test_sweep_to_treasury() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_claim_window(&100);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_round(&env, &client, &alice, &bob);
+
+    // Too early to sweep
+    advance_to(&env, 112);
+    let result = client.try_sweep_unclaimed(&1, &SweepDestination::Treasury);
+    assert_eq!(result, Err(Ok(ContractError::ClaimWindowOpen)));
+
+    advance_to(&env, 113);
+    assert_eq!(client.sweep_unclaimed(&1, &SweepDestination::Treasury), 20_0000000);
+
+    assert_eq!(client.get_treasury(), 20_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 0);
+    assert!(client.get_claimable_rounds(&alice).is_empty());
+    assert!(client.get_expiring_rounds().is_empty());
+    assert_eq!(client.get_claim_deadline(&1), None);
+}
+
+#[test]
+fn test_sweep_only_takes_unclaimed() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_claim_window(&100);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &10_0000000, &BetSide::Up);
+    client.place_bet(&charlie, &10_0000000, &BetSide::Up);
+    client.place_bet(&bob, &20_0000000, &BetSide::Down);
+    advance_to(&env, 12);
+    client.resolve_round(&1_5000000);
+
+    // Alice claims in time, Charlie does not
+    client.claim_winnings(&alice);
+    advance_to(&env, 200);
+
+    assert_eq!(client.sweep_unclaimed(&1, &SweepDestination::Treasury), 20_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+}
+
+#[test]
+fn test_sweep_to_jackpot_boosts_next_round() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_claim_window(&100);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    play_winning_round(&env, &client, &alice, &bob);
+    advance_to(&env, 113);
+    client.sweep_unclaimed(&1, &SweepDestination::Jackpot);
+    assert_eq!(client.get_jackpot(), 20_0000000);
+
+    // Next round carries the jackpot and its winners split it pro-rata
+    client.create_round(&1_0000000, &None);
+    assert_eq!(client.get_jackpot(), 0);
+    assert_eq!(client.get_active_round().unwrap().jackpot, 20_0000000);
+
+    client.place_bet(&bob, &10_0000000, &BetSide::Up);
+    client.place_bet(&charlie, &10_0000000, &BetSide::Down);
+    advance_to(&env, 125);
+    client.resolve_round(&1_5000000);
+
+    // 10 stake + 10 from Charlie + 20 jackpot
+    assert_eq!(client.get_pending_winnings(&bob), 40_0000000);
+}
+
+#[test]
+fn test_jackpot_rolls_over_without_winners() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_claim_window(&100);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_round(&env, &client, &alice, &bob);
+    advance_to(&env, 113);
+    client.sweep_unclaimed(&1, &SweepDestination::Jackpot);
+
+    // Price unchanged: everyone is refunded and the jackpot carries over
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&bob, &10_0000000, &BetSide::Up);
+    advance_to(&env, 125);
+    client.resolve_round(&1_0000000);
+
+    assert_eq!(client.get_pending_winnings(&bob), 10_0000000);
+    assert_eq!(client.get_jackpot(), 20_0000000);
+}
+
+#[test]
+fn test_claimed_rounds_stop_expiring() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_claim_window(&100);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    // A round without bets leaves nothing to sweep
+    client.create_round(&1_0000000, &None);
+    advance_to(&env, client.get_active_round().unwrap().end_ledger);
+    client.resolve_round(&1_5000000);
+    assert!(client.get_expiring_rounds().is_empty());
+
+    play_winning_round(&env, &client, &alice, &bob);
+    play_winning_round(&env, &client, &alice, &bob);
+    assert_eq!(client.get_expiring_rounds(), vec![&env, 2, 3]);
+
+    client.claim_round(&alice, &2);
+    assert_eq!(client.get_expiring_rounds(), vec![&env, 3]);
+
+    client.claim_winnings(&alice);
+    assert!(client.get_expiring_rounds().is_empty());
+}
@@ -0,0 +1,205 @@
+//! Tests for per-round claims and checked claim arithmetic.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, DataKey, RoundClaim};
+use soroban_sdk::{testutils::{Address as _, Events, Ledger as _}, vec, Address, Env};
+
+/// Plays `rounds` Up/Down rounds where `winner` bets UP against `loser` and the price rises
+fn play_winning_rounds(
+    env: &Env,
+    client: &VirtualTokenContractClient,
+    winner: &Address,
+    loser: &Address,
+    rounds: u32,
+) {
+    for _ in 0..rounds {
+        client.create_round(&1_0000000, &None);
+        client.place_bet(winner, &10_0000000, &BetSide::Up);
+        client.place_bet(loser, &10_0000000, &BetSide::Down);
+
+        let end_ledger = client.get_active_round().unwrap().end_ledger;
+        env.ledger().with_mut(|li| {
+            li.sequence_number = end_ledger;
+        });
+        client.resolve_round(&1_5000000);
+    }
+}
+
+#[test]
+fn test_round_ids_increment() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.create_round(&1_0000000, &None);
+    assert_eq!(client.get_active_round().unwrap().round_id, 1);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+    });
+    client.resolve_round(&1_0000000);
+
+    client.create_round(&1_0000000, &None);
+    assert_eq!(client.get_active_round().unwrap().round_id, 2);
+}
+
+#[test]
+fn test_claimable_rounds_tracked_per_round() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_rounds(&env, &client, &alice, &bob, 2);
+
+    assert_eq!(
+        client.get_claimable_rounds(&alice),
+        vec![
+            &env,
+            RoundClaim { round_id: 1, amount: 20_0000000 },
+            RoundClaim { round_id: 2, amount: 20_0000000 },
+        ]
+    );
+    assert_eq!(client.get_pending_winnings(&alice), 40_0000000);
+    assert!(client.get_claimable_rounds(&bob).is_empty());
+}
+
+#[test]
+fn test_claim_round() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_rounds(&env, &client, &alice, &bob, 2);
+    let balance_before = client.balance(&alice);
+
+    // Claim the second round only
+    assert_eq!(client.claim_round(&alice, &2), 20_0000000);
+    assert_eq!(client.balance(&alice), balance_before + 20_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 20_0000000);
+    assert_eq!(
+        client.get_claimable_rounds(&alice),
+        vec![&env, RoundClaim { round_id: 1, amount: 20_0000000 }]
+    );
+
+    // Same round cannot be claimed twice
+    let result = client.try_claim_round(&alice, &2);
+    assert_eq!(result, Err(Ok(ContractError::NoWinningsForRound)));
+
+    // Losers have nothing to claim
+    let result = client.try_claim_round(&bob, &1);
+    assert_eq!(result, Err(Ok(ContractError::NoWinningsForRound)));
+}
+
+#[test]
+fn test_claim_all_respects_max_rounds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_rounds(&env, &client, &alice, &bob, 3);
+
+    // Oldest two rounds first
+    assert_eq!(client.claim_all(&alice, &2), 40_0000000);
+    assert_eq!(
+        client.get_claimable_rounds(&alice),
+        vec![&env, RoundClaim { round_id: 3, amount: 20_0000000 }]
+    );
+
+    // Limit larger than remaining claims the rest
+    assert_eq!(client.claim_all(&alice, &10), 20_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 0);
+    assert!(client.get_claimable_rounds(&alice).is_empty());
+
+    // Nothing left
+    assert_eq!(client.claim_all(&alice, &10), 0);
+}
+
+#[test]
+fn test_claim_winnings_emits_event_per_round() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_winning_rounds(&env, &client, &alice, &bob, 2);
+
+    assert_eq!(client.claim_winnings(&alice), 40_0000000);
+
+    // The last invocation emitted one claim event per round
+    assert_eq!(env.events().all().len(), 2);
+    assert!(client.get_claimable_rounds(&alice).is_empty());
+}
+
+#[test]
+fn test_claim_winnings_overflow_returns_error() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::Balance(user.clone()), &i128::MAX);
+        env.storage().persistent().set(&DataKey::PendingWinnings(user.clone()), &1i128);
+    });
+
+    let result = client.try_claim_winnings(&user);
+    assert_eq!(result, Err(Ok(ContractError::Overflow)));
+
+    // Nothing moved
+    assert_eq!(client.balance(&user), i128::MAX);
+    assert_eq!(client.get_pending_winnings(&user), 1);
+}
@@ -1,7 +1,7 @@
 //! Tests for boundary conditions and unusual scenarios.
 
 use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
-use crate::types::{BetSide, DataKey, Round, UserPosition};
+use crate::types::{BetSide, DataKey, Round, RoundMode, RoundOutcome, RoundResult, UserPosition};
 use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env, Map};
 
 #[test]
@@ -33,6 +33,7 @@ fn test_round_with_no_participants() {
     
     // Should clear round without errors
     assert_eq!(client.get_active_round(), None);
+    assert_eq!(client.get_round_result(&1).unwrap().outcome, RoundOutcome::Empty);
 }
 
 #[test]
@@ -68,9 +69,54 @@ fn test_round_with_only_one_side() {
     // Resolve - UP wins but no losers to take from
     client.resolve_round(&1_5000000);
     
-    // Winners should only get their bets back (no losing pool to split)
+    // One-sided round is voided: stakes are refunded and nobody scores a win
     assert_eq!(client.get_pending_winnings(&alice), 100_0000000);
     assert_eq!(client.get_pending_winnings(&bob), 150_0000000);
+    assert_eq!(client.get_user_stats(&alice).total_wins, 0);
+    assert_eq!(client.get_user_stats(&bob).total_wins, 0);
+    assert_eq!(
+        client.get_round_result(&1),
+        Some(RoundResult {
+            round_id: 1,
+            mode: RoundMode::UpDown,
+            price_start: 1_0000000,
+            final_price: 1_5000000,
+            outcome: RoundOutcome::Voided,
+            resolved_ledger: 12,
+        })
+    );
+}
+
+#[test]
+fn test_round_with_empty_winning_side() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin, &oracle);
+    client.set_fee_bps(&500);
+    client.mint_initial(&alice);
+    
+    // Only DOWN is backed, then the price goes UP
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Down);
+    
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+    });
+    client.resolve_round(&1_5000000);
+    
+    // Stake comes back in full, with no fee and no loss recorded
+    assert_eq!(client.get_pending_winnings(&alice), 100_0000000);
+    assert_eq!(client.get_treasury(), 0);
+    assert_eq!(client.get_user_stats(&alice).total_losses, 0);
+    assert_eq!(client.get_round_result(&1).unwrap().outcome, RoundOutcome::Voided);
 }
 
 #[test]
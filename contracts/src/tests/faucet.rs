@@ -0,0 +1,245 @@
+//! Tests for the vXLM faucet: refill floor, cooldown, daily caps and total supply.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, FaucetConfig};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+const FLOOR: i128 = 1000_0000000;
+const DAY: u32 = 17_280;
+
+fn config(cooldown_ledgers: u32, user_daily_cap: i128, global_daily_cap: i128) -> FaucetConfig {
+    FaucetConfig {
+        floor: FLOOR,
+        cooldown_ledgers,
+        user_daily_cap,
+        global_daily_cap,
+    }
+}
+
+fn advance(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+    });
+}
+
+/// Spends part of the user's balance on a tournament entry fee
+fn spend(env: &Env, client: &VirtualTokenContractClient, user: &Address, amount: i128) {
+    let tournament_id = client.create_tournament(&amount, &1, &1, &vec![env, 10_000]);
+    client.join_tournament(user, &tournament_id);
+}
+
+#[test]
+fn test_first_claim_tops_up_to_floor() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    assert_eq!(client.get_faucet_config(), config(DAY, FLOOR, 0));
+    assert_eq!(client.get_faucet_claim(&alice), None);
+
+    assert_eq!(client.claim_faucet(&alice), FLOOR);
+    assert_eq!(client.balance(&alice), FLOOR);
+    assert_eq!(client.get_total_supply(), FLOOR);
+
+    let claim = client.get_faucet_claim(&alice).unwrap();
+    assert_eq!(claim.last_ledger, env.ledger().sequence());
+    assert_eq!(claim.claimed_today, FLOOR);
+
+    // Already at the floor, even once the cooldown has passed
+    advance(&env, DAY);
+    assert_eq!(client.try_claim_faucet(&alice), Err(Ok(ContractError::FaucetLimitReached)));
+}
+
+#[test]
+fn test_refill_after_cooldown() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.set_faucet_config(&config(100, 0, 0));
+    client.claim_faucet(&alice);
+    spend(&env, &client, &alice, 600_0000000);
+
+    assert_eq!(client.try_claim_faucet(&alice), Err(Ok(ContractError::CooldownActive)));
+
+    advance(&env, 99);
+    assert_eq!(client.try_claim_faucet(&alice), Err(Ok(ContractError::CooldownActive)));
+
+    advance(&env, 1);
+    assert_eq!(client.claim_faucet(&alice), 600_0000000);
+    assert_eq!(client.balance(&alice), FLOOR);
+    assert_eq!(client.get_total_supply(), 1600_0000000);
+}
+
+#[test]
+fn test_user_daily_cap() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.set_faucet_config(&config(10, 1500_0000000, 0));
+    client.claim_faucet(&alice);
+    spend(&env, &client, &alice, 800_0000000);
+
+    // Only 500 of today's 1500 are left
+    advance(&env, 10);
+    assert_eq!(client.claim_faucet(&alice), 500_0000000);
+
+    advance(&env, 10);
+    assert_eq!(client.try_claim_faucet(&alice), Err(Ok(ContractError::FaucetLimitReached)));
+
+    // The cap resets on the next faucet day
+    advance(&env, DAY);
+    assert_eq!(client.claim_faucet(&alice), 300_0000000);
+    assert_eq!(client.balance(&alice), FLOOR);
+}
+
+#[test]
+fn test_global_daily_cap() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.set_faucet_config(&config(DAY, 0, 1500_0000000));
+    assert_eq!(client.claim_faucet(&alice), FLOOR);
+    assert_eq!(client.claim_faucet(&bob), 500_0000000);
+    assert_eq!(client.try_claim_faucet(&carol), Err(Ok(ContractError::FaucetLimitReached)));
+
+    advance(&env, DAY);
+    assert_eq!(client.claim_faucet(&carol), FLOOR);
+}
+
+#[test]
+fn test_pending_winnings_count_toward_floor() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.set_faucet_config(&config(0, 0, 0));
+    client.claim_faucet(&alice);
+    client.claim_faucet(&bob);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &500_0000000, &BetSide::Up);
+    client.place_bet(&bob, &500_0000000, &BetSide::Down);
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // Alice's unclaimed 1000 winnings put her over the floor; Bob lost his stake
+    assert_eq!(client.try_claim_faucet(&alice), Err(Ok(ContractError::FaucetLimitReached)));
+    assert_eq!(client.claim_faucet(&bob), 500_0000000);
+}
+
+#[test]
+fn test_mint_initial_uses_faucet() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    assert_eq!(client.mint_initial(&alice), FLOOR);
+    assert_eq!(client.mint_initial(&alice), FLOOR);
+    assert_eq!(client.get_total_supply(), FLOOR);
+    assert!(client.get_faucet_claim(&alice).is_some());
+}
+
+#[test]
+fn test_mint_initial_reports_exhausted_global_cap() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.set_faucet_config(&config(DAY, 0, FLOOR));
+    assert_eq!(client.mint_initial(&alice), FLOOR);
+    assert_eq!(client.try_mint_initial(&bob), Err(Ok(ContractError::FaucetLimitReached)));
+    assert_eq!(client.balance(&bob), 0);
+
+    advance(&env, DAY);
+    assert_eq!(client.mint_initial(&bob), FLOOR);
+}
+
+#[test]
+fn test_invalid_faucet_config() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    let mut bad = config(DAY, 0, 0);
+    bad.floor = 0;
+    assert_eq!(client.try_set_faucet_config(&bad), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(client.try_set_faucet_config(&config(DAY, -1, 0)), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(client.try_set_faucet_config(&config(DAY, 0, -1)), Err(Ok(ContractError::InvalidParameters)));
+
+    client.set_faucet_config(&config(10, 0, 0));
+    assert_eq!(client.get_config().faucet, config(10, 0, 0));
+}
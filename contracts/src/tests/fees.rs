@@ -0,0 +1,154 @@
+//! Tests for the protocol fee and treasury accounting.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::BetSide;
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env};
+
+#[test]
+fn test_fee_defaults_to_zero() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    assert_eq!(client.get_fee_bps(), 0);
+    assert_eq!(client.get_treasury(), 0);
+}
+
+#[test]
+fn test_set_fee_bps_bounds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    // More than 100% should fail
+    let result = client.try_set_fee_bps(&10_001);
+    assert_eq!(result, Err(Ok(ContractError::InvalidFee)));
+
+    client.set_fee_bps(&250);
+    assert_eq!(client.get_fee_bps(), 250);
+}
+
+#[test]
+fn test_updown_fee_taken_from_losing_pool() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_fee_bps(&1_000); // 10%
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+    });
+    client.resolve_round(&1_5000000);
+
+    // Alice: 100 + (100 - 10% fee) = 190
+    assert_eq!(client.get_pending_winnings(&alice), 190_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 0);
+    assert_eq!(client.get_treasury(), 10_0000000);
+}
+
+#[test]
+fn test_precision_fee_charged_on_losers_stake() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_fee_bps(&500); // 5%
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2000, &Some(1));
+    client.place_precision_prediction(&alice, &100_0000000, &2250);
+    client.place_precision_prediction(&bob, &200_0000000, &2100);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+    });
+    client.resolve_round(&2240);
+
+    // Fee is 5% of Bob's losing 200 = 10; Alice gets the remaining 290
+    assert_eq!(client.get_treasury(), 10_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 290_0000000);
+}
+
+#[test]
+fn test_withdraw_treasury() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let ops = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_fee_bps(&1_000); // 10%
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+    });
+    client.resolve_round(&1_5000000);
+    assert_eq!(client.get_treasury(), 10_0000000);
+
+    assert_eq!(client.try_withdraw_treasury(&ops, &0), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(
+        client.try_withdraw_treasury(&ops, &10_0000001),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+
+    assert_eq!(client.withdraw_treasury(&ops, &4_0000000), 6_0000000);
+    assert_eq!(client.balance(&ops), 4_0000000);
+    assert_eq!(client.get_treasury(), 6_0000000);
+
+    // Moving fees out of the treasury leaves the total supply unchanged
+    let supply = client.get_supply_breakdown();
+    assert_eq!(supply.treasury, 6_0000000);
+    assert_eq!(supply.circulating + supply.pending + supply.treasury, supply.total_supply);
+}
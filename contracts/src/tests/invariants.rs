@@ -0,0 +1,409 @@
+//! Property-based invariant tests for token conservation.
+//!
+//! Random sequences of mints, bets, predictions, sealed commitments, bucket stakes,
+//! resolutions and claims are replayed against the contract. After every step the
+//! suite checks that:
+//! - balances + pending winnings + open stakes + treasury + jackpot + dust == total minted
+//! - `get_supply_breakdown` agrees with those totals and its parts sum to the total supply
+//! - no balance or pending amount is negative
+//! - wins + losses across all users equals settled participations
+//!
+//! Dust is the documented loss from integer division when splitting a pool
+//! (strictly less than one stroop per winner, two with Precision exact-hit bonuses).
+
+extern crate std;
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::types::{
+    BetSide, PenaltyDestination, PrecisionPayout, PrecisionTieRules, RemainderPolicy, RoundMode, RoundOutcome,
+    Scoring, ScoringKernel, StakeWeighting, Tolerance,
+};
+use proptest::prelude::*;
+use soroban_sdk::{testutils::{Address as _, EnvTestConfig, Ledger as _}, Address, BytesN, Env};
+use std::vec::Vec;
+
+const USERS: usize = 6;
+
+#[derive(Clone, Debug)]
+enum Op {
+    Mint(usize),
+    CreateRound { mode: u32, price: u128 },
+    PlaceBet { user: usize, amount: i128, up: bool },
+    PlacePrecision { user: usize, amount: i128, price: u128 },
+    PlaceBucket { user: usize, amount: i128, bucket: u32 },
+    Commit { user: usize, amount: i128, price: u128 },
+    Reveal { user: usize, price: u128 },
+    IncreaseBet { user: usize, amount: i128 },
+    WithdrawBet { user: usize, amount: i128 },
+    Advance(u32),
+    Resolve(u128),
+    Claim(usize),
+}
+
+/// Precision payout policy without an `Env`, built into a `PrecisionPayout` by the harness
+#[derive(Clone, Debug)]
+enum PayoutSpec {
+    Even,
+    StakeWeighted { max_win_bps: u32, refund: bool },
+    Tiered(Vec<u32>),
+    Scored { gaussian: bool, width: u128 },
+}
+
+fn payout_strategy() -> impl Strategy<Value = PayoutSpec> {
+    prop_oneof![
+        Just(PayoutSpec::Even),
+        (1u32..=30_000, any::<bool>())
+            .prop_map(|(max_win_bps, refund)| PayoutSpec::StakeWeighted { max_win_bps, refund }),
+        prop::collection::vec(1u32..=100, 1..=4).prop_map(|weights| {
+            // Scale arbitrary weights into a table summing to exactly 10_000 bps
+            let total: u32 = weights.iter().sum();
+            let mut tiers: Vec<u32> = weights.iter().map(|w| w * 10_000 / total).collect();
+            let assigned: u32 = tiers.iter().sum();
+            tiers[0] += 10_000 - assigned;
+            PayoutSpec::Tiered(tiers)
+        }),
+        (any::<bool>(), 1u128..=50_000).prop_map(|(gaussian, width)| PayoutSpec::Scored { gaussian, width }),
+    ]
+}
+
+fn tie_rules_strategy() -> impl Strategy<Value = PrecisionTieRules> {
+    (
+        prop_oneof![
+            Just(Tolerance::Exact),
+            (0u128..=20_000).prop_map(Tolerance::Units),
+            (0u32..=10_000).prop_map(Tolerance::Bps),
+        ],
+        0u32..=50_000,
+        0u32..=10_000,
+    )
+        .prop_map(|(tolerance, bonus_multiplier_bps, bonus_pool_bps)| PrecisionTieRules {
+            tolerance,
+            bonus_multiplier_bps,
+            bonus_pool_bps,
+        })
+}
+
+fn bet_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        1 => (0..USERS).prop_map(Op::Mint),
+        4 => (0..USERS, 1i128..=600_0000000, any::<bool>())
+            .prop_map(|(user, amount, up)| Op::PlaceBet { user, amount, up }),
+        4 => (0..USERS, 1i128..=600_0000000, 1u128..=5)
+            .prop_map(|(user, amount, price)| Op::PlacePrecision { user, amount, price: price * 1_0000 }),
+        3 => (0..USERS, 1i128..=600_0000000, 1u128..=5)
+            .prop_map(|(user, amount, price)| Op::Commit { user, amount, price: price * 1_0000 }),
+        4 => (0..USERS, 1i128..=600_0000000, 0u32..=5)
+            .prop_map(|(user, amount, bucket)| Op::PlaceBucket { user, amount, bucket }),
+        2 => (0..USERS, 1i128..=300_0000000).prop_map(|(user, amount)| Op::IncreaseBet { user, amount }),
+        2 => (0..USERS, 1i128..=300_0000000).prop_map(|(user, amount)| Op::WithdrawBet { user, amount }),
+    ]
+}
+
+/// One round's worth of operations: open, bet (including wrong-mode and
+/// unfunded attempts), reveals (possibly mismatched or too early), an early or
+/// on-time resolve, a guaranteed resolve, claims
+fn round_strategy() -> impl Strategy<Value = Vec<Op>> {
+    (
+        0u32..=2,
+        1u128..=5,
+        prop::collection::vec(bet_strategy(), 0..10),
+        0u32..=14,
+        prop::collection::vec((0..USERS, 1u128..=5), 0..USERS),
+        1u128..=5,
+        prop::collection::vec(0..USERS, 0..USERS),
+    )
+        .prop_map(|(mode, start, bets, wait, reveals, end, claims)| {
+            // Precision prices use 4 decimals, other modes use stroops
+            let scale = if mode == 1 { 1_0000 } else { 1_0000000 };
+            let mut ops = Vec::new();
+            ops.push(Op::CreateRound { mode, price: start * scale });
+            ops.extend(bets);
+            ops.push(Op::Advance(wait));
+            ops.extend(reveals.into_iter().map(|(user, price)| Op::Reveal { user, price: price * 1_0000 }));
+            ops.push(Op::Resolve(end * scale));
+            ops.push(Op::Advance(12));
+            ops.push(Op::Resolve(end * scale));
+            ops.extend(claims.into_iter().map(Op::Claim));
+            ops
+        })
+}
+
+struct Harness<'a> {
+    env: &'a Env,
+    client: VirtualTokenContractClient<'a>,
+    users: Vec<Address>,
+    minted: i128,
+    dust: i128,
+    settled: u32,
+}
+
+impl<'a> Harness<'a> {
+    fn new(
+        env: &'a Env,
+        fee_bps: u32,
+        penalty_bps: u32,
+        penalty_to_pool: bool,
+        payout: &PayoutSpec,
+        tie_rules: &PrecisionTieRules,
+    ) -> Self {
+        let contract_id = env.register(VirtualTokenContract, ());
+        let client = VirtualTokenContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let oracle = Address::generate(env);
+        client.initialize(&admin, &oracle);
+        client.set_fee_bps(&fee_bps);
+        let destination = if penalty_to_pool { PenaltyDestination::Pool } else { PenaltyDestination::Treasury };
+        client.set_exit_penalty(&penalty_bps, &destination);
+        let payout = match payout {
+            PayoutSpec::Even => PrecisionPayout::Even,
+            PayoutSpec::StakeWeighted { max_win_bps, refund } => {
+                let remainder = if *refund { RemainderPolicy::Refund } else { RemainderPolicy::Rollover };
+                PrecisionPayout::StakeWeighted(StakeWeighting { max_win_bps: *max_win_bps, remainder })
+            },
+            PayoutSpec::Tiered(tiers) => PrecisionPayout::Tiered(soroban_sdk::Vec::from_slice(env, tiers)),
+            PayoutSpec::Scored { gaussian, width } => {
+                let kernel = if *gaussian { ScoringKernel::Gaussian } else { ScoringKernel::InverseDistance };
+                PrecisionPayout::Scored(Scoring { kernel, width: *width })
+            },
+        };
+        client.set_precision_payout(&payout);
+        client.set_precision_tie_rules(tie_rules);
+
+        let users = (0..USERS).map(|_| Address::generate(env)).collect();
+
+        Harness { env, client, users, minted: 0, dust: 0, settled: 0 }
+    }
+
+    fn apply(&mut self, op: &Op) {
+        match op {
+            Op::Mint(user) => {
+                let user = &self.users[*user];
+                let before = self.client.balance(user);
+                let minted = self.client.mint_initial(user);
+                if minted != before {
+                    self.minted += minted - before;
+                }
+            },
+            Op::CreateRound { mode, price } => {
+                // Overwriting an active round discards its stakes, so only open one when none is active
+                if self.client.get_active_round().is_none() {
+                    let _ = self.client.try_create_round(price, &Some(*mode));
+                }
+            },
+            Op::PlaceBet { user, amount, up } => {
+                let side = if *up { BetSide::Up } else { BetSide::Down };
+                let _ = self.client.try_place_bet(&self.users[*user], amount, &side);
+            },
+            Op::PlacePrecision { user, amount, price } => {
+                let _ = self.client.try_place_precision_prediction(&self.users[*user], amount, price);
+            },
+            Op::PlaceBucket { user, amount, bucket } => {
+                let _ = self.client.try_place_bucket_bet(&self.users[*user], amount, bucket);
+            },
+            Op::Commit { user, amount, price } => {
+                let round_id = match self.client.get_active_round() {
+                    Some(round) => round.round_id,
+                    None => return,
+                };
+                let hash = self.client.compute_commitment(&self.users[*user], price, &self.salt(*user));
+                let _ = self.client.try_commit_prediction(&self.users[*user], &round_id, &hash, amount);
+            },
+            Op::Reveal { user, price } => {
+                let round_id = match self.client.get_active_round() {
+                    Some(round) => round.round_id,
+                    None => return,
+                };
+                let _ = self.client.try_reveal_prediction(&self.users[*user], &round_id, price, &self.salt(*user));
+            },
+            Op::IncreaseBet { user, amount } => {
+                self.try_adjust(*user, *amount, true);
+            },
+            Op::WithdrawBet { user, amount } => {
+                self.try_adjust(*user, *amount, false);
+            },
+            Op::Advance(ledgers) => {
+                self.env.ledger().with_mut(|li| {
+                    li.sequence_number += ledgers;
+                });
+            },
+            Op::Resolve(final_price) => self.resolve(*final_price),
+            Op::Claim(user) => {
+                let _ = self.client.try_claim_winnings(&self.users[*user]);
+            },
+        }
+    }
+
+    /// Deterministic per-user salt so reveals can reproduce the commitment
+    fn salt(&self, user: usize) -> BytesN<32> {
+        BytesN::from_array(self.env, &[user as u8; 32])
+    }
+
+    /// Increases or withdraws the user's bet on whichever round is active
+    fn try_adjust(&self, user: usize, amount: i128, increase: bool) {
+        let round_id = match self.client.get_active_round() {
+            Some(round) => round.round_id,
+            None => return,
+        };
+        let user = &self.users[user];
+        if increase {
+            let _ = self.client.try_increase_bet(user, &round_id, &amount);
+        } else {
+            let _ = self.client.try_withdraw_bet(user, &round_id, &amount);
+        }
+    }
+
+    /// Resolves the active round and accounts for expected dust and settled participations
+    fn resolve(&mut self, final_price: u128) {
+        let round = match self.client.get_active_round() {
+            Some(round) => round,
+            None => return,
+        };
+
+        let (settled, max_dust) = match round.mode {
+            RoundMode::UpDown => {
+                let participants = self.client.get_updown_positions().len();
+                if final_price == round.price_start || round.pool_up == 0 || round.pool_down == 0 {
+                    // Unchanged price refunds everyone; one-sided rounds are voided and refunded
+                    (0, 0)
+                } else {
+                    (participants, participants as i128)
+                }
+            },
+            RoundMode::Precision => {
+                // Exact-hit bonuses round down once more per participant
+                let participants = self.client.get_precision_predictions().len();
+                (participants, 2 * participants as i128)
+            },
+            RoundMode::Buckets => {
+                let participants = self.client.get_bucket_positions().len();
+                let change_bps = ((final_price as i128 - round.price_start as i128) * 10_000).div_euclid(round.price_start as i128);
+                let winning_bucket = round.bucket_bounds.iter().filter(|bound| change_bps >= *bound as i128).count();
+                if round.bucket_pools.get(winning_bucket as u32).unwrap_or(0) == 0 {
+                    // Empty winning bucket refunds everyone
+                    (0, 0)
+                } else {
+                    (participants, participants as i128)
+                }
+            },
+        };
+
+        let before = self.total_value();
+        if self.client.try_resolve_round(&final_price).is_err() {
+            return;
+        }
+        let lost = before - self.total_value();
+
+        assert!(lost >= 0, "resolution created value: lost {}", lost);
+        assert!(lost <= max_dust, "resolution lost {} beyond dust bound {}", lost, max_dust);
+
+        // Only rounds that paid winners count toward stats
+        let result = self.client.get_round_result(&round.round_id).unwrap();
+        assert_eq!(settled > 0, result.outcome == RoundOutcome::Settled, "unexpected outcome {:?}", result.outcome);
+
+        self.dust += lost;
+        self.settled += settled;
+    }
+
+    fn open_stakes(&self) -> i128 {
+        match self.client.get_active_round() {
+            Some(round) => round.jackpot + match round.mode {
+                RoundMode::UpDown => round.pool_up + round.pool_down,
+                RoundMode::Precision => {
+                    let revealed = self.client.get_precision_predictions().iter().map(|p| p.amount).sum::<i128>();
+                    let sealed = self
+                        .users
+                        .iter()
+                        .filter_map(|user| self.client.get_user_commitment(user))
+                        .map(|c| c.amount)
+                        .sum::<i128>();
+                    revealed + sealed
+                },
+                RoundMode::Buckets => round.bucket_pools.iter().sum::<i128>(),
+            },
+            None => 0,
+        }
+    }
+
+    fn total_value(&self) -> i128 {
+        let held: i128 = self
+            .users
+            .iter()
+            .map(|u| self.client.balance(u) + self.client.get_pending_winnings(u))
+            .sum();
+        held + self.open_stakes() + self.client.get_treasury() + self.client.get_jackpot()
+    }
+
+    fn check_invariants(&self) {
+        for user in &self.users {
+            assert!(self.client.balance(user) >= 0, "negative balance");
+            assert!(self.client.get_pending_winnings(user) >= 0, "negative pending winnings");
+        }
+
+        assert_eq!(self.total_value() + self.dust, self.minted, "token conservation violated");
+
+        let supply = self.client.get_supply_breakdown();
+        assert_eq!(supply.total_supply, self.minted, "total supply does not match minted");
+        assert_eq!(
+            supply.circulating,
+            self.users.iter().map(|u| self.client.balance(u)).sum::<i128>(),
+            "circulating supply does not match balances"
+        );
+        assert_eq!(
+            supply.pending,
+            self.users.iter().map(|u| self.client.get_pending_winnings(u)).sum::<i128>(),
+            "pending supply does not match pending winnings"
+        );
+        assert_eq!(supply.staked, self.open_stakes(), "staked supply does not match open stakes");
+        assert_eq!(supply.dust, self.dust, "dust does not match resolution losses");
+        assert_eq!(
+            supply.circulating + supply.staked + supply.pending + supply.treasury + supply.jackpot
+                + supply.tournament_pools + supply.dust,
+            supply.total_supply,
+            "supply breakdown does not sum to total supply"
+        );
+
+        let recorded: u32 = self
+            .users
+            .iter()
+            .map(|u| {
+                let stats = self.client.get_user_stats(u);
+                stats.total_wins + stats.total_losses
+            })
+            .sum();
+        assert_eq!(recorded, self.settled, "stats do not match settled participations");
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_token_conservation(
+        fee_bps in 0u32..=1_000,
+        penalty_bps in 0u32..=2_000,
+        penalty_to_pool in any::<bool>(),
+        payout in payout_strategy(),
+        tie_rules in tie_rules_strategy(),
+        funded in 2..=USERS,
+        rounds in prop::collection::vec(round_strategy(), 1..6),
+    ) {
+        // Hundreds of cases would otherwise each write a snapshot file
+        let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+        env.mock_all_auths();
+
+        let mut harness = Harness::new(&env, fee_bps, penalty_bps, penalty_to_pool, &payout, &tie_rules);
+        harness.check_invariants();
+
+        // Fund most users up front; later Mint ops exercise the one-shot guard
+        for user in 0..funded {
+            harness.apply(&Op::Mint(user));
+            harness.check_invariants();
+        }
+
+        for op in rounds.iter().flatten() {
+            harness.apply(op);
+            harness.check_invariants();
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Tests for the on-chain top-K leaderboards.
+
+extern crate std;
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::types::{BetSide, LeaderboardEntry, LeaderboardKind, RoundMode};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+fn entry(user: &Address, score: i128) -> LeaderboardEntry {
+    LeaderboardEntry { user: user.clone(), score }
+}
+
+#[test]
+fn test_empty_leaderboards() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    assert!(client.get_leaderboard(&LeaderboardKind::NetProfit, &10).is_empty());
+    assert!(client.get_leaderboard(&LeaderboardKind::BestStreak, &10).is_empty());
+    assert!(client.get_leaderboard(&LeaderboardKind::PrecisionAccuracy, &10).is_empty());
+}
+
+#[test]
+fn test_net_profit_and_streak_boards() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &50_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    assert_eq!(
+        client.get_leaderboard(&LeaderboardKind::NetProfit, &10),
+        vec![&env, entry(&alice, 50_0000000), entry(&bob, -50_0000000)]
+    );
+    assert_eq!(client.get_leaderboard(&LeaderboardKind::NetProfit, &1), vec![&env, entry(&alice, 50_0000000)]);
+    // Bob has no streak yet so he stays off the streak board
+    assert_eq!(client.get_leaderboard(&LeaderboardKind::BestStreak, &10), vec![&env, entry(&alice, 1)]);
+
+    // Bob overtakes Alice and moves up without being listed twice
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &200_0000000, &BetSide::Up);
+    client.place_bet(&bob, &400_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&5000000);
+
+    assert_eq!(
+        client.get_leaderboard(&LeaderboardKind::NetProfit, &10),
+        vec![&env, entry(&bob, 150_0000000), entry(&alice, -150_0000000)]
+    );
+    assert_eq!(
+        client.get_leaderboard(&LeaderboardKind::BestStreak, &10),
+        vec![&env, entry(&alice, 1), entry(&bob, 1)]
+    );
+}
+
+#[test]
+fn test_precision_accuracy_needs_three_rounds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    for (round, (alice_price, bob_price)) in [(2302, 2310), (2296, 2320), (2301, 2300)].iter().enumerate() {
+        client.create_round(&2300, &Some(1));
+        client.place_precision_prediction(&alice, &10_0000000, alice_price);
+        client.place_precision_prediction(&bob, &10_0000000, bob_price);
+
+        let end_ledger = client.get_active_round().unwrap().end_ledger;
+        env.ledger().with_mut(|li| {
+            li.sequence_number = end_ledger;
+        });
+        client.resolve_round(&2300);
+
+        if round < 2 {
+            assert!(client.get_leaderboard(&LeaderboardKind::PrecisionAccuracy, &10).is_empty());
+        }
+    }
+
+    // Alice averages (2 + 4 + 1) / 3 = 2, Bob (10 + 20 + 0) / 3 = 10; lower error ranks first
+    assert_eq!(
+        client.get_leaderboard(&LeaderboardKind::PrecisionAccuracy, &10),
+        vec![&env, entry(&alice, 2), entry(&bob, 10)]
+    );
+}
+
+#[test]
+fn test_leaderboard_is_bounded() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    let users: std::vec::Vec<Address> = (0..12).map(|_| Address::generate(&env)).collect();
+    env.as_contract(&client.address, || {
+        for (i, user) in users.iter().enumerate() {
+            let payout = 100 + i as i128;
+            VirtualTokenContract::_update_stats_win(&env, user.clone(), &RoundMode::UpDown, 100, payout, None);
+        }
+    });
+
+    // Only the ten most profitable remain, best first
+    let board = client.get_leaderboard(&LeaderboardKind::NetProfit, &20);
+    assert_eq!(board.len(), 10);
+    assert_eq!(board.get(0).unwrap(), entry(&users[11], 11));
+    assert_eq!(board.get(9).unwrap(), entry(&users[2], 2));
+}
+
+#[test]
+fn test_next_candidate_promoted_when_leader_falls() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    // Profits of 1 to 11: users[10] leads and users[0] is 11th, just off the board
+    let users: std::vec::Vec<Address> = (0..11).map(|_| Address::generate(&env)).collect();
+    env.as_contract(&client.address, || {
+        for (i, user) in users.iter().enumerate() {
+            let payout = 101 + i as i128;
+            VirtualTokenContract::_update_stats_win(&env, user.clone(), &RoundMode::UpDown, 100, payout, None);
+        }
+    });
+    let board = client.get_leaderboard(&LeaderboardKind::NetProfit, &10);
+    assert_eq!(board.get(0).unwrap(), entry(&users[10], 11));
+    assert!(!board.iter().any(|e| e.user == users[0]));
+
+    // The leader loses 100 and falls to last; the 11th user moves up without playing again
+    env.as_contract(&client.address, || {
+        VirtualTokenContract::_update_stats_loss(&env, users[10].clone(), &RoundMode::UpDown, 100, 0, None);
+    });
+    let board = client.get_leaderboard(&LeaderboardKind::NetProfit, &10);
+    assert_eq!(board.len(), 10);
+    assert_eq!(board.get(0).unwrap(), entry(&users[9], 10));
+    assert_eq!(board.get(9).unwrap(), entry(&users[0], 1));
+}
@@ -7,4 +7,24 @@ mod lifecycle;
 mod edge_cases;
 mod mode_tests;
 mod windows;
-
+mod fees;
+mod invariants;
+mod claims;
+mod claim_deadlines;
+mod bet_adjustments;
+mod bet_limits;
+mod quotes;
+mod buckets;
+mod precision_payouts;
+mod sealed_predictions;
+mod precision_ties;
+mod user_stats;
+mod leaderboards;
+mod seasons;
+mod tournaments;
+mod achievements;
+mod referrals;
+mod faucet;
+mod supply;
+mod token_admin;
+mod syndicates;
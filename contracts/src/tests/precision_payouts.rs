@@ -0,0 +1,691 @@
+//! Tests for Precision payout policies.
+
+extern crate std;
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{PrecisionPayout, PrecisionRank, RemainderPolicy, Scoring, ScoringKernel, StakeWeighting};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+fn stake_weighted(max_win_bps: u32, remainder: RemainderPolicy) -> PrecisionPayout {
+    PrecisionPayout::StakeWeighted(StakeWeighting { max_win_bps, remainder })
+}
+
+#[test]
+fn test_default_policy_is_even() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.create_round(&2297, &Some(1));
+
+    assert_eq!(client.get_precision_payout(), PrecisionPayout::Even);
+    assert_eq!(client.get_active_round().unwrap().precision_payout, PrecisionPayout::Even);
+    assert_eq!(client.get_config().precision_payout, PrecisionPayout::Even);
+}
+
+#[test]
+fn test_invalid_stake_weighting() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.create_round(&2297, &Some(1));
+
+    let result = client.try_set_precision_payout(&stake_weighted(0, RemainderPolicy::Refund));
+    assert_eq!(result, Err(Ok(ContractError::InvalidPayoutPolicy)));
+}
+
+#[test]
+fn test_min_stake_sniping_refunds_remainder() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&stake_weighted(10_000, RemainderPolicy::Refund));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    // Alice stakes little and guesses exactly; Bob stakes a lot and misses
+    client.place_precision_prediction(&alice, &10_0000000, &2300);
+    client.place_precision_prediction(&bob, &100_0000000, &2500);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // Alice's winnings are matched 1:1 with her stake, Bob gets the rest back
+    assert_eq!(client.get_pending_winnings(&alice), 20_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 90_0000000);
+    assert_eq!(client.get_user_stats(&bob).total_losses, 1);
+}
+
+#[test]
+fn test_remainder_rolls_over() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&stake_weighted(10_000, RemainderPolicy::Rollover));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &10_0000000, &2300);
+    client.place_precision_prediction(&bob, &100_0000000, &2500);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 20_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 0);
+    assert_eq!(client.get_jackpot(), 90_0000000);
+}
+
+#[test]
+fn test_tied_winners_share_by_stake() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&stake_weighted(10_000, RemainderPolicy::Refund));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &10_0000000, &2290);
+    client.place_precision_prediction(&charlie, &30_0000000, &2310);
+    client.place_precision_prediction(&bob, &100_0000000, &2500);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 20_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 60_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 60_0000000);
+}
+
+#[test]
+fn test_cap_not_reached_pays_whole_pot() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&stake_weighted(50_000, RemainderPolicy::Refund));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &30_0000000, &2300);
+    client.place_precision_prediction(&bob, &10_0000000, &2500);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 40_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 0);
+}
+
+#[test]
+fn test_stake_weighted_with_fee() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&stake_weighted(10_000, RemainderPolicy::Refund));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+    client.set_fee_bps(&1_000);
+
+    client.place_precision_prediction(&alice, &10_0000000, &2300);
+    client.place_precision_prediction(&bob, &100_0000000, &2500);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // 10 fee on Bob's stake, 10 won by Alice, 80 back to Bob
+    assert_eq!(client.get_treasury(), 10_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 20_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 80_0000000);
+}
+
+#[test]
+fn test_policy_fixed_at_round_creation() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    client.set_precision_payout(&stake_weighted(10_000, RemainderPolicy::Refund));
+
+    client.place_precision_prediction(&alice, &10_0000000, &2300);
+    client.place_precision_prediction(&bob, &100_0000000, &2500);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // The active round still uses winner-takes-all
+    assert_eq!(client.get_pending_winnings(&alice), 110_0000000);
+}
+
+fn podium(env: &Env) -> PrecisionPayout {
+    PrecisionPayout::Tiered(vec![env, 6_000, 2_500, 1_500])
+}
+
+#[test]
+fn test_invalid_tier_tables() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.create_round(&2297, &Some(1));
+
+    let eleven_tiers = vec![&env, 1_000, 1_000, 1_000, 1_000, 1_000, 1_000, 1_000, 1_000, 1_000, 500, 500];
+    for tiers in [vec![&env], vec![&env, 6_000, 3_000], vec![&env, 10_000, 0], eleven_tiers] {
+        let result = client.try_set_precision_payout(&PrecisionPayout::Tiered(tiers));
+        assert_eq!(result, Err(Ok(ContractError::InvalidPayoutPolicy)));
+    }
+}
+
+#[test]
+fn test_tiered_payout_and_ranks() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&podium(&env));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+    client.mint_initial(&dave);
+
+    client.place_precision_prediction(&alice, &100_0000000, &2310);
+    client.place_precision_prediction(&bob, &100_0000000, &2300);
+    client.place_precision_prediction(&charlie, &100_0000000, &2400);
+    client.place_precision_prediction(&dave, &100_0000000, &2280);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // 400 pot: 60% / 25% / 15%, fourth place loses
+    assert_eq!(client.get_pending_winnings(&bob), 240_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 100_0000000);
+    assert_eq!(client.get_pending_winnings(&dave), 60_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+    assert_eq!(client.get_user_stats(&dave).total_wins, 1);
+    assert_eq!(client.get_user_stats(&charlie).total_losses, 1);
+
+    let ranks = client.get_round_ranks(&1);
+    assert_eq!(ranks.len(), 4);
+    assert_eq!(
+        ranks.get(0).unwrap(),
+        PrecisionRank {
+            user: bob.clone(),
+            predicted_price: 2300,
+            amount: 100_0000000,
+            error: 0,
+            rank: 1,
+            payout: 240_0000000,
+        }
+    );
+    assert_eq!(ranks.get(1).unwrap().user, alice);
+    assert_eq!(ranks.get(2).unwrap().user, dave);
+    assert_eq!(ranks.get(3).unwrap().rank, 4);
+    assert_eq!(ranks.get(3).unwrap().payout, 0);
+}
+
+#[test]
+fn test_tiered_tie_across_boundary() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&podium(&env));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+    client.mint_initial(&dave);
+
+    // Bob wins; Alice and Charlie tie for second and share 2nd + 3rd; Dave is fourth
+    client.place_precision_prediction(&bob, &100_0000000, &2300);
+    client.place_precision_prediction(&alice, &100_0000000, &2290);
+    client.place_precision_prediction(&charlie, &100_0000000, &2310);
+    client.place_precision_prediction(&dave, &100_0000000, &2000);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&bob), 240_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 80_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 80_0000000);
+    assert_eq!(client.get_pending_winnings(&dave), 0);
+
+    let ranks = client.get_round_ranks(&1);
+    assert_eq!(ranks.get(1).unwrap().rank, 2);
+    assert_eq!(ranks.get(2).unwrap().rank, 2);
+    assert_eq!(ranks.get(3).unwrap().rank, 4);
+}
+
+#[test]
+fn test_tie_spilling_past_table() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&PrecisionPayout::Tiered(vec![&env, 7_000, 3_000]));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    // Three-way tie for second spans one remaining tier, so all three split it
+    client.place_precision_prediction(&bob, &100_0000000, &2300);
+    client.place_precision_prediction(&alice, &100_0000000, &2290);
+    client.place_precision_prediction(&charlie, &100_0000000, &2310);
+    let dave = Address::generate(&env);
+    client.mint_initial(&dave);
+    client.place_precision_prediction(&dave, &200_0000000, &2290);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&bob), 350_0000000);
+    assert_eq!(client.get_pending_winnings(&alice), 50_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 50_0000000);
+    assert_eq!(client.get_pending_winnings(&dave), 50_0000000);
+}
+
+#[test]
+fn test_tiered_unfilled_tiers_rescaled() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&podium(&env));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    // Only two players: the pot is shared 60:25
+    client.place_precision_prediction(&alice, &85_0000000, &2300);
+    client.place_precision_prediction(&bob, &85_0000000, &2400);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 120_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 50_0000000);
+}
+
+#[test]
+fn test_ranks_recorded_for_even_policy() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &10_0000000, &2400);
+    client.place_precision_prediction(&bob, &10_0000000, &2300);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2310);
+
+    let ranks = client.get_round_ranks(&1);
+    assert_eq!(ranks.get(0).unwrap().user, bob);
+    assert_eq!(ranks.get(0).unwrap().error, 10);
+    assert_eq!(ranks.get(0).unwrap().payout, 20_0000000);
+    assert_eq!(ranks.get(1).unwrap().rank, 2);
+    assert!(client.get_round_ranks(&2).is_empty());
+}
+
+fn scored(kernel: ScoringKernel, width: u128) -> PrecisionPayout {
+    PrecisionPayout::Scored(Scoring { kernel, width })
+}
+
+#[test]
+fn test_invalid_scoring_width() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.create_round(&2297, &Some(1));
+
+    for width in [0, 100_000_000] {
+        let result = client.try_set_precision_payout(&scored(ScoringKernel::Gaussian, width));
+        assert_eq!(result, Err(Ok(ContractError::InvalidPayoutPolicy)));
+    }
+}
+
+#[test]
+fn test_score_kernels() {
+    let inverse = Scoring { kernel: ScoringKernel::InverseDistance, width: 50 };
+    assert_eq!(VirtualTokenContract::_score(&inverse, 0), 1_000_000);
+    assert_eq!(VirtualTokenContract::_score(&inverse, 50), 500_000);
+    assert_eq!(VirtualTokenContract::_score(&inverse, 150), 250_000);
+
+    // Fixed-point Gaussian stays within a few millionths of the real curve
+    let gaussian = Scoring { kernel: ScoringKernel::Gaussian, width: 50 };
+    for error in [0u128, 10, 25, 50, 75, 100, 170, 250, 499, 500] {
+        let x = error as f64 / 50.0;
+        let expected = ((-x * x / 2.0).exp() * 1_000_000.0) as i128;
+        let actual = VirtualTokenContract::_score(&gaussian, error);
+        assert!((actual - expected).abs() <= 3, "error {}: {} vs {}", error, actual, expected);
+    }
+    assert_eq!(VirtualTokenContract::_score(&gaussian, 501), 0);
+}
+
+#[test]
+fn test_inverse_distance_payout() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&scored(ScoringKernel::InverseDistance, 10));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &100_0000000, &2300);
+    client.place_precision_prediction(&bob, &100_0000000, &2310);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // Scores 1.0 and 0.5 split the 200 pot 2:1
+    assert_eq!(client.get_pending_winnings(&alice), 133_3333333);
+    assert_eq!(client.get_pending_winnings(&bob), 66_6666666);
+
+    // Only a payout above the stake counts as a win
+    assert_eq!(client.get_user_stats(&alice).total_wins, 1);
+    assert_eq!(client.get_user_stats(&bob).total_losses, 1);
+    assert_eq!(client.get_round_ranks(&1).get(1).unwrap().payout, 66_6666666);
+}
+
+#[test]
+fn test_scored_payout_weighs_stake() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&scored(ScoringKernel::InverseDistance, 10));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    // Equal error, so the pot follows stake
+    client.place_precision_prediction(&alice, &10_0000000, &2290);
+    client.place_precision_prediction(&bob, &30_0000000, &2310);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 10_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 30_0000000);
+}
+
+#[test]
+fn test_gaussian_ignores_distant_guesses() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&scored(ScoringKernel::Gaussian, 10));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &100_0000000, &2300);
+    client.place_precision_prediction(&bob, &100_0000000, &2300);
+    client.place_precision_prediction(&charlie, &100_0000000, &2500);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+}
+
+#[test]
+fn test_scored_falls_back_to_closest() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&scored(ScoringKernel::Gaussian, 1));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    // Both guesses are beyond ten widths, so nobody scores
+    client.place_precision_prediction(&alice, &50_0000000, &2320);
+    client.place_precision_prediction(&bob, &50_0000000, &2400);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 100_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 0);
+}
@@ -0,0 +1,352 @@
+//! Tests for Precision tie bands and the exact-hit bonus.
+
+extern crate std;
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{PrecisionPayout, PrecisionTieRules, RemainderPolicy, StakeWeighting, Tolerance};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env};
+
+fn rules(tolerance: Tolerance, bonus_multiplier_bps: u32, bonus_pool_bps: u32) -> PrecisionTieRules {
+    PrecisionTieRules { tolerance, bonus_multiplier_bps, bonus_pool_bps }
+}
+
+#[test]
+fn test_default_rules() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Exact, 0, 0));
+    client.create_round(&2297, &Some(1));
+
+    assert_eq!(client.get_precision_tie_rules(), rules(Tolerance::Exact, 0, 0));
+    assert_eq!(client.get_config().precision_tie_rules, rules(Tolerance::Exact, 0, 0));
+}
+
+#[test]
+fn test_invalid_rules() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Exact, 0, 0));
+    client.create_round(&2297, &Some(1));
+
+    for invalid in [
+        rules(Tolerance::Bps(10_001), 0, 0),
+        rules(Tolerance::Units(100_000_000), 0, 0),
+        rules(Tolerance::Exact, 5_000, 10_001),
+    ] {
+        assert_eq!(
+            client.try_set_precision_tie_rules(&invalid),
+            Err(Ok(ContractError::InvalidPayoutPolicy))
+        );
+    }
+}
+
+#[test]
+fn test_rules_fixed_at_round_creation() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Units(5), 0, 0));
+    client.create_round(&2297, &Some(1));
+
+    client.set_precision_tie_rules(&rules(Tolerance::Bps(100), 5_000, 2_000));
+
+    assert_eq!(client.get_active_round().unwrap().tie_rules, rules(Tolerance::Units(5), 0, 0));
+    assert_eq!(client.get_precision_tie_rules(), rules(Tolerance::Bps(100), 5_000, 2_000));
+}
+
+#[test]
+fn test_exact_ties_by_default() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Exact, 0, 0));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &100_0000000, &2303);
+    client.place_precision_prediction(&bob, &100_0000000, &2308);
+    client.place_precision_prediction(&charlie, &100_0000000, &2350);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 300_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 0);
+}
+
+#[test]
+fn test_unit_band_shares_first_place() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Units(5), 0, 0));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    // Errors 3 and 8 are within 5 units of each other; 50 is not
+    client.place_precision_prediction(&alice, &100_0000000, &2303);
+    client.place_precision_prediction(&bob, &100_0000000, &2308);
+    client.place_precision_prediction(&charlie, &100_0000000, &2350);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+    assert_eq!(client.get_user_stats(&bob).total_wins, 1);
+
+    let ranks: std::vec::Vec<u32> = client.get_round_ranks(&1).iter().map(|entry| entry.rank).collect();
+    assert_eq!(ranks, [1, 1, 3]);
+}
+
+#[test]
+fn test_bps_band_scales_with_price() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Bps(100), 0, 0));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    // 1% of 2300 is 23 units, so errors 10 and 20 tie while 50 does not
+    client.place_precision_prediction(&alice, &100_0000000, &2310);
+    client.place_precision_prediction(&bob, &100_0000000, &2280);
+    client.place_precision_prediction(&charlie, &100_0000000, &2350);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_pending_winnings(&alice), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+}
+
+#[test]
+fn test_band_pools_tiers() {
+    let env = Env::default();
+    let tiers = PrecisionPayout::Tiered(soroban_sdk::vec![&env, 6_000, 3_000, 1_000]);
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&tiers);
+    client.set_precision_tie_rules(&rules(Tolerance::Units(5), 0, 0));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &100_0000000, &2303);
+    client.place_precision_prediction(&bob, &100_0000000, &2308);
+    client.place_precision_prediction(&charlie, &100_0000000, &2350);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // Alice and Bob share the first two tiers; Charlie keeps third
+    assert_eq!(client.get_pending_winnings(&alice), 135_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 135_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 30_0000000);
+}
+
+#[test]
+fn test_perfect_bonus_before_split() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Units(10), 5_000, 2_000));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &100_0000000, &2300);
+    client.place_precision_prediction(&bob, &100_0000000, &2310);
+    client.place_precision_prediction(&charlie, &100_0000000, &2400);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // Fund is 20% of 300; Alice's 50% bonus (50) fits, the other 250 splits with Bob
+    assert_eq!(client.get_pending_winnings(&alice), 175_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 125_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+}
+
+#[test]
+fn test_perfect_bonus_scaled_to_fund() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_tie_rules(&rules(Tolerance::Exact, 20_000, 1_000));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &100_0000000, &2300);
+    client.place_precision_prediction(&bob, &300_0000000, &2300);
+    client.place_precision_prediction(&charlie, &100_0000000, &2400);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // Bonuses of 200 and 600 are cut to a 50 fund pro-rata, then 450 splits evenly
+    assert_eq!(client.get_pending_winnings(&alice), 237_5000000);
+    assert_eq!(client.get_pending_winnings(&bob), 262_5000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 0);
+}
+
+#[test]
+fn test_perfect_bonus_with_stake_weighting() {
+    let env = Env::default();
+    let weighted = PrecisionPayout::StakeWeighted(StakeWeighting {
+        max_win_bps: 3_000,
+        remainder: RemainderPolicy::Refund,
+    });
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_precision_payout(&weighted);
+    client.set_precision_tie_rules(&rules(Tolerance::Exact, 5_000, 2_000));
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+
+    client.create_round(&2297, &Some(1));
+
+    client.place_precision_prediction(&alice, &100_0000000, &2300);
+    client.place_precision_prediction(&charlie, &100_0000000, &2400);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // Bonus is 20% of the losers' 100; Alice then wins her capped 30 and Charlie gets the rest back
+    assert_eq!(client.get_pending_winnings(&alice), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&charlie), 50_0000000);
+}
@@ -0,0 +1,177 @@
+//! Tests for payout quotes and live round odds.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetQuote, BetSide, PenaltyDestination, RoundOdds};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env};
+
+#[test]
+fn test_quote_bet_even_pools() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Down);
+
+    // 100 UP against 100 DOWN doubles the stake
+    assert_eq!(
+        client.quote_bet(&1, &BetSide::Up, &100_0000000),
+        BetQuote { payout: 200_0000000, net_winnings: 100_0000000, multiplier_bps: 20_000 }
+    );
+
+    // Joining the crowded side returns only the stake
+    assert_eq!(
+        client.quote_bet(&1, &BetSide::Down, &100_0000000),
+        BetQuote { payout: 100_0000000, net_winnings: 0, multiplier_bps: 10_000 }
+    );
+}
+
+#[test]
+fn test_quote_matches_resolution() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_fee_bps(&250);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&charlie);
+    client.create_round(&1_0000000, &None);
+
+    client.place_bet(&bob, &33_3333333, &BetSide::Up);
+    client.place_bet(&charlie, &77_7777777, &BetSide::Down);
+
+    // Uneven amounts exercise the integer rounding
+    let quote = client.quote_bet(&1, &BetSide::Up, &12_3456789);
+    client.place_bet(&alice, &12_3456789, &BetSide::Up);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+    });
+    client.resolve_round(&1_5000000);
+
+    assert_eq!(client.get_pending_winnings(&alice), quote.payout);
+    assert_eq!(quote.net_winnings, quote.payout - 12_3456789);
+}
+
+#[test]
+fn test_round_odds() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.set_fee_bps(&1_000);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.create_round(&1_0000000, &None);
+
+    assert_eq!(
+        client.get_round_odds(&1),
+        RoundOdds { round_id: 1, pool_up: 0, pool_down: 0, up_multiplier_bps: 0, down_multiplier_bps: 0 }
+    );
+
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &300_0000000, &BetSide::Down);
+
+    // UP: 1 + 300 * 0.9 / 100 = 3.7x, DOWN: 1 + 100 * 0.9 / 300 = 1.3x
+    assert_eq!(
+        client.get_round_odds(&1),
+        RoundOdds {
+            round_id: 1,
+            pool_up: 100_0000000,
+            pool_down: 300_0000000,
+            up_multiplier_bps: 37_000,
+            down_multiplier_bps: 13_000,
+        }
+    );
+}
+
+#[test]
+fn test_one_sided_round_quotes_refund() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.set_fee_bps(&1_000);
+    client.set_exit_penalty(&1_000, &PenaltyDestination::Pool);
+
+    // Alice's exit penalty leaves a 10 vXLM jackpot in a round with no UP bets
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.withdraw_bet(&alice, &1, &100_0000000);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    let quote = client.quote_bet(&1, &BetSide::Down, &100_0000000);
+    assert_eq!(quote, BetQuote { payout: 100_0000000, net_winnings: 0, multiplier_bps: 10_000 });
+
+    assert_eq!(
+        client.get_round_odds(&1),
+        RoundOdds { round_id: 1, pool_up: 0, pool_down: 100_0000000, up_multiplier_bps: 0, down_multiplier_bps: 10_000 }
+    );
+
+    // Resolution refunds the lone side, matching the quote
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&5000000);
+    assert_eq!(client.get_pending_winnings(&bob), 100_0000000);
+}
+
+#[test]
+fn test_quote_errors() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    assert_eq!(client.try_get_round_odds(&1), Err(Ok(ContractError::NoActiveRound)));
+
+    client.create_round(&1_0000000, &None);
+    assert_eq!(client.try_quote_bet(&1, &BetSide::Up, &0), Err(Ok(ContractError::InvalidBetAmount)));
+    assert_eq!(client.try_quote_bet(&2, &BetSide::Up, &1), Err(Ok(ContractError::RoundMismatch)));
+
+    client.create_round(&1_0000000, &Some(1));
+    assert_eq!(client.try_get_round_odds(&2), Err(Ok(ContractError::WrongModeForPrediction)));
+}
@@ -0,0 +1,184 @@
+//! Tests for referral registration and fee-share rewards.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, ReferralStats};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env};
+
+#[test]
+fn test_register_referral() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    assert_eq!(client.get_referrer(&alice), None);
+
+    client.register_referral(&alice, &referrer);
+    client.register_referral(&bob, &referrer);
+
+    assert_eq!(client.get_referrer(&alice), Some(referrer.clone()));
+    assert_eq!(
+        client.get_referral_stats(&referrer),
+        ReferralStats { referred_users: 2, total_rewards: 0 }
+    );
+    assert_eq!(client.try_register_referral(&alice, &bob), Err(Ok(ContractError::AlreadyRegistered)));
+}
+
+#[test]
+fn test_no_self_referral_or_cycles() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    assert_eq!(client.try_register_referral(&alice, &alice), Err(Ok(ContractError::InvalidReferrer)));
+
+    // alice -> bob -> dave: neither bob nor dave may name alice
+    client.register_referral(&alice, &bob);
+    client.register_referral(&bob, &dave);
+    assert_eq!(client.try_register_referral(&dave, &alice), Err(Ok(ContractError::InvalidReferrer)));
+    assert_eq!(client.try_register_referral(&dave, &bob), Err(Ok(ContractError::InvalidReferrer)));
+}
+
+#[test]
+fn test_referral_closed_after_first_bet() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    assert_eq!(client.try_register_referral(&alice, &referrer), Err(Ok(ContractError::RegistrationClosed)));
+
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // Played rounds keep it closed once positions are cleared
+    assert_eq!(client.try_register_referral(&alice, &referrer), Err(Ok(ContractError::RegistrationClosed)));
+    assert_eq!(client.try_register_referral(&bob, &referrer), Err(Ok(ContractError::RegistrationClosed)));
+}
+
+#[test]
+fn test_referrer_earns_fee_share() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    client.set_fee_bps(&1_000);
+    client.set_referral_bps(&5_000);
+    client.register_referral(&alice, &referrer);
+
+    // Alice's winning stake is a quarter of the winning side, so a quarter of the fee is hers
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&dave, &300_0000000, &BetSide::Up);
+    client.place_bet(&bob, &200_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    assert_eq!(client.get_pending_winnings(&referrer), 2_5000000);
+    assert_eq!(client.get_treasury(), 17_5000000);
+    assert_eq!(client.get_referral_stats(&referrer).total_rewards, 2_5000000);
+
+    // Winners' payouts are unchanged by the referral
+    assert_eq!(client.get_pending_winnings(&alice), 145_0000000);
+
+    assert_eq!(client.claim_winnings(&referrer), 2_5000000);
+    assert_eq!(client.balance(&referrer), 2_5000000);
+}
+
+#[test]
+fn test_no_reward_when_referred_user_loses() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.set_fee_bps(&1_000);
+    client.set_referral_bps(&5_000);
+    client.register_referral(&alice, &referrer);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Down);
+    client.place_bet(&bob, &100_0000000, &BetSide::Up);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    assert_eq!(client.get_pending_winnings(&referrer), 0);
+    assert_eq!(client.get_treasury(), 10_0000000);
+    assert_eq!(client.try_set_referral_bps(&10_001), Err(Ok(ContractError::InvalidFee)));
+}
@@ -2,7 +2,7 @@
 
 use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
 use crate::errors::ContractError;
-use crate::types::{BetSide, DataKey, PrecisionPrediction, Round, UserPosition};
+use crate::types::{BetSide, DataKey, PrecisionPrediction, Round, RoundMode, RoundOutcome, UserPosition};
 use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env, Map, Vec};
 
 #[test]
@@ -79,6 +79,7 @@ fn test_resolve_round_price_unchanged() {
     
     // Round should be cleared
     assert_eq!(client.get_active_round(), None);
+    assert_eq!(client.get_round_result(&1).unwrap().outcome, RoundOutcome::Refunded);
 }
 
 #[test]
@@ -146,6 +147,7 @@ fn test_resolve_round_price_went_up() {
     assert_eq!(client.get_pending_winnings(&alice), 150_0000000);
     assert_eq!(client.get_pending_winnings(&bob), 300_0000000);
     assert_eq!(client.get_pending_winnings(&charlie), 0); // Lost
+    assert_eq!(client.get_round_result(&1).unwrap().outcome, RoundOutcome::Settled);
     
     // Check stats: Alice and Bob won, Charlie lost
     let alice_stats = client.get_user_stats(&alice);
@@ -264,7 +266,7 @@ fn test_user_stats_tracking() {
     
     // Simulate a win
     env.as_contract(&contract_id, || {
-        VirtualTokenContract::_update_stats_win(&env, alice.clone());
+        VirtualTokenContract::_update_stats_win(&env, alice.clone(), &RoundMode::UpDown, 100, 150, None);
     });
     
     let stats = client.get_user_stats(&alice);
@@ -274,7 +276,7 @@ fn test_user_stats_tracking() {
     
     // Another win - streak increases
     env.as_contract(&contract_id, || {
-        VirtualTokenContract::_update_stats_win(&env, alice.clone());
+        VirtualTokenContract::_update_stats_win(&env, alice.clone(), &RoundMode::UpDown, 100, 150, None);
     });
     
     let stats = client.get_user_stats(&alice);
@@ -284,7 +286,7 @@ fn test_user_stats_tracking() {
     
     // A loss - streak resets
     env.as_contract(&contract_id, || {
-        VirtualTokenContract::_update_stats_loss(&env, alice.clone());
+        VirtualTokenContract::_update_stats_loss(&env, alice.clone(), &RoundMode::UpDown, 100, 0, None);
     });
     
     let stats = client.get_user_stats(&alice);
@@ -0,0 +1,308 @@
+//! Tests for commit-reveal sealed predictions in Precision mode.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::PrecisionPrediction;
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, BytesN, Env};
+
+fn salt(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+fn advance_to(env: &Env, ledger: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number = ledger;
+    });
+}
+
+#[test]
+fn test_commit_hides_prediction() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    client.create_round(&2297, &Some(1));
+
+    let hash = client.compute_commitment(&alice, &2300, &salt(&env, 1));
+    client.commit_prediction(&alice, &1, &hash, &100_0000000);
+
+    assert!(client.get_precision_predictions().is_empty());
+    assert_eq!(client.get_user_commitment(&alice).unwrap().hash, hash);
+    assert_eq!(client.balance(&alice), 900_0000000);
+
+    // One stake per user, sealed or not
+    assert_eq!(
+        client.try_commit_prediction(&alice, &1, &hash, &1),
+        Err(Ok(ContractError::AlreadyBet))
+    );
+    assert_eq!(
+        client.try_place_precision_prediction(&alice, &1, &2300),
+        Err(Ok(ContractError::AlreadyBet))
+    );
+}
+
+#[test]
+fn test_reveal_flow() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    let hash = client.compute_commitment(&alice, &2300, &salt(&env, 1));
+    client.commit_prediction(&alice, &1, &hash, &100_0000000);
+    client.place_precision_prediction(&bob, &100_0000000, &2400);
+
+    // Reveals only open once betting closes at ledger 6
+    assert_eq!(
+        client.try_reveal_prediction(&alice, &1, &2300, &salt(&env, 1)),
+        Err(Ok(ContractError::RevealNotOpen))
+    );
+
+    advance_to(&env, 6);
+    assert_eq!(
+        client.try_reveal_prediction(&alice, &1, &2310, &salt(&env, 1)),
+        Err(Ok(ContractError::CommitmentMismatch))
+    );
+    assert_eq!(
+        client.try_reveal_prediction(&alice, &1, &2300, &salt(&env, 2)),
+        Err(Ok(ContractError::CommitmentMismatch))
+    );
+
+    client.reveal_prediction(&alice, &1, &2300, &salt(&env, 1));
+    assert_eq!(client.get_user_commitment(&alice), None);
+    assert_eq!(
+        client.get_user_precision_prediction(&alice),
+        Some(PrecisionPrediction { user: alice.clone(), predicted_price: 2300, amount: 100_0000000 })
+    );
+    assert_eq!(
+        client.try_reveal_prediction(&alice, &1, &2300, &salt(&env, 1)),
+        Err(Ok(ContractError::NoCommitment))
+    );
+
+    advance_to(&env, 12);
+    client.resolve_round(&2300);
+    assert_eq!(client.get_pending_winnings(&alice), 200_0000000);
+}
+
+#[test]
+fn test_copied_commitment_cannot_be_revealed() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    let hash = client.compute_commitment(&alice, &2300, &salt(&env, 1));
+    client.commit_prediction(&alice, &1, &hash, &10_0000000);
+    client.commit_prediction(&bob, &1, &hash, &10_0000000);
+
+    advance_to(&env, 6);
+    client.reveal_prediction(&alice, &1, &2300, &salt(&env, 1));
+
+    // The hash binds the committer, so Bob cannot replay Alice's reveal
+    assert_eq!(
+        client.try_reveal_prediction(&bob, &1, &2300, &salt(&env, 1)),
+        Err(Ok(ContractError::CommitmentMismatch))
+    );
+}
+
+#[test]
+fn test_unrevealed_stake_forfeited_to_winners() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+
+    let hash = client.compute_commitment(&bob, &2300, &salt(&env, 9));
+    client.commit_prediction(&bob, &1, &hash, &50_0000000);
+    client.place_precision_prediction(&alice, &100_0000000, &2400);
+
+    advance_to(&env, 12);
+    client.resolve_round(&2300);
+
+    // Bob never revealed, so his stake joins the pot Alice wins
+    assert_eq!(client.get_pending_winnings(&alice), 150_0000000);
+    assert_eq!(client.get_pending_winnings(&bob), 0);
+    assert_eq!(client.get_user_commitment(&bob), None);
+}
+
+#[test]
+fn test_forfeit_rolls_over_without_reveals() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    client.create_round(&2297, &Some(1));
+
+    let hash = client.compute_commitment(&alice, &2300, &salt(&env, 1));
+    client.commit_prediction(&alice, &1, &hash, &50_0000000);
+
+    advance_to(&env, 12);
+    client.resolve_round(&2300);
+
+    assert_eq!(client.get_jackpot(), 50_0000000);
+}
+
+#[test]
+fn test_reveal_window() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    client.create_round(&2297, &Some(1));
+
+    // Default windows are 6 bet / 12 run ledgers
+    assert_eq!(client.try_set_reveal_window(&7), Err(Ok(ContractError::InvalidDuration)));
+    client.set_reveal_window(&3);
+    assert_eq!(client.get_reveal_window(), Some(3));
+    assert_eq!(client.get_config().reveal_ledgers, Some(3));
+    assert_eq!(client.try_set_windows(&10, &12), Err(Ok(ContractError::InvalidDuration)));
+
+    client.create_round(&2297, &Some(1));
+    let round = client.get_active_round().unwrap();
+    assert_eq!(round.reveal_end_ledger, 9);
+
+    let hash = client.compute_commitment(&alice, &2300, &salt(&env, 1));
+    client.commit_prediction(&alice, &2, &hash, &10_0000000);
+
+    advance_to(&env, 9);
+    assert_eq!(
+        client.try_reveal_prediction(&alice, &2, &2300, &salt(&env, 1)),
+        Err(Ok(ContractError::RevealWindowClosed))
+    );
+
+    // Clearing the window lets new rounds reveal until end_ledger
+    client.set_reveal_window(&0);
+    assert_eq!(client.get_reveal_window(), None);
+}
+
+#[test]
+fn test_plaintext_rejected_in_sealed_mode() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.set_reveal_window(&3);
+    client.create_round(&2297, &Some(1));
+
+    let hash = client.compute_commitment(&alice, &2300, &salt(&env, 1));
+    client.commit_prediction(&alice, &1, &hash, &10_0000000);
+
+    // Bob cannot bet in the clear to copy or bracket sealed guesses
+    assert_eq!(
+        client.try_place_precision_prediction(&bob, &10_0000000, &2301),
+        Err(Ok(ContractError::WrongModeForPrediction))
+    );
+    assert_eq!(client.try_predict_price(&bob, &2301, &10_0000000), Err(Ok(ContractError::WrongModeForPrediction)));
+    assert!(client.get_precision_predictions().is_empty());
+
+    // Without a reveal window, plaintext guesses are accepted again
+    client.set_reveal_window(&0);
+    client.create_round(&2297, &Some(1));
+    client.place_precision_prediction(&bob, &10_0000000, &2301);
+    assert_eq!(client.get_precision_predictions().len(), 1);
+}
+
+#[test]
+fn test_commit_rejected() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    client.create_round(&2297, &Some(1));
+    let hash = client.compute_commitment(&alice, &2300, &salt(&env, 1));
+
+    assert_eq!(client.try_commit_prediction(&alice, &2, &hash, &1), Err(Ok(ContractError::RoundMismatch)));
+    assert_eq!(client.try_commit_prediction(&alice, &1, &hash, &0), Err(Ok(ContractError::InvalidBetAmount)));
+
+    advance_to(&env, 6);
+    assert_eq!(client.try_commit_prediction(&alice, &1, &hash, &1), Err(Ok(ContractError::RoundEnded)));
+
+    client.create_round(&1_0000000, &None);
+    assert_eq!(
+        client.try_commit_prediction(&alice, &2, &hash, &1),
+        Err(Ok(ContractError::WrongModeForPrediction))
+    );
+}
@@ -0,0 +1,225 @@
+//! Tests for seasons: per-season stats, leaderboards and prizes.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, DataKey, LeaderboardEntry, LeaderboardKind, RoundMode, SeasonKey};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+/// Plays an Up/Down round in which `winner` stakes 100 on UP, `loser` 50 on DOWN and the price rises
+fn play_round(env: &Env, client: &VirtualTokenContractClient, winner: &Address, loser: &Address) {
+    client.create_round(&1_0000000, &None);
+    client.place_bet(winner, &100_0000000, &BetSide::Up);
+    client.place_bet(loser, &50_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+    client.claim_winnings(winner);
+}
+
+#[test]
+fn test_start_season() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    assert_eq!(client.get_active_season(), None);
+
+    let tiers = vec![&env, 7_000, 3_000];
+    assert_eq!(client.start_season(&500_0000000, &tiers, &LeaderboardKind::NetProfit), 1);
+
+    let season = client.get_active_season().unwrap();
+    assert_eq!(season.season_id, 1);
+    assert_eq!(season.end_ledger, None);
+    assert_eq!(season.prize_pool, 500_0000000);
+    assert_eq!(season.prize_tiers, tiers);
+    assert_eq!(client.get_season(&1), Some(season));
+
+    assert_eq!(
+        client.try_start_season(&0, &vec![&env, 10_000], &LeaderboardKind::BestStreak),
+        Err(Ok(ContractError::SeasonActive))
+    );
+}
+
+#[test]
+fn test_invalid_prizes() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    let kind = LeaderboardKind::NetProfit;
+    assert_eq!(client.try_start_season(&-1, &vec![&env, 10_000], &kind), Err(Ok(ContractError::InvalidPrizes)));
+    assert_eq!(client.try_start_season(&1, &vec![&env], &kind), Err(Ok(ContractError::InvalidPrizes)));
+    assert_eq!(client.try_start_season(&1, &vec![&env, 5_000, 4_000], &kind), Err(Ok(ContractError::InvalidPrizes)));
+    assert_eq!(client.try_start_season(&1, &vec![&env, 10_000, 0], &kind), Err(Ok(ContractError::InvalidPrizes)));
+    assert_eq!(client.try_end_season(), Err(Ok(ContractError::NoActiveSeason)));
+}
+
+#[test]
+fn test_season_stats_separate_from_lifetime() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    play_round(&env, &client, &alice, &bob);
+    client.start_season(&0, &vec![&env, 10_000], &LeaderboardKind::NetProfit);
+    play_round(&env, &client, &bob, &alice);
+
+    // Lifetime stats cover both rounds; season stats only the one played during the season
+    assert_eq!(client.get_user_stats(&alice).total_wins, 1);
+    assert_eq!(client.get_user_stats(&alice).total_losses, 1);
+    assert_eq!(client.get_season_stats(&1, &alice).total_wins, 0);
+    assert_eq!(client.get_season_stats(&1, &alice).total_losses, 1);
+    assert_eq!(client.get_season_stats(&1, &bob).net_pnl, 50_0000000);
+
+    assert_eq!(
+        client.get_season_leaderboard(&1, &LeaderboardKind::NetProfit, &10),
+        vec![
+            &env,
+            LeaderboardEntry { user: bob.clone(), score: 50_0000000 },
+            LeaderboardEntry { user: alice.clone(), score: -50_0000000 },
+        ]
+    );
+}
+
+#[test]
+fn test_end_season_pays_prizes() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.start_season(&100_0000000, &vec![&env, 7_000, 3_000], &LeaderboardKind::NetProfit);
+    play_round(&env, &client, &alice, &bob);
+
+    let alice_before = client.balance(&alice);
+    let bob_before = client.balance(&bob);
+    assert_eq!(client.end_season(), 100_0000000);
+
+    assert_eq!(client.balance(&alice), alice_before + 70_0000000);
+    assert_eq!(client.balance(&bob), bob_before + 30_0000000);
+
+    let season = client.get_season(&1).unwrap();
+    assert_eq!(season.end_ledger, Some(12));
+    assert_eq!(season.prizes_paid, 100_0000000);
+    assert_eq!(client.get_active_season(), None);
+
+    // Rounds between seasons only count toward lifetime stats
+    play_round(&env, &client, &alice, &bob);
+    assert_eq!(client.get_season_stats(&1, &alice).total_wins, 1);
+    assert_eq!(client.get_user_stats(&alice).total_wins, 2);
+
+    // The next season starts from a clean slate
+    assert_eq!(client.start_season(&0, &vec![&env, 10_000], &LeaderboardKind::BestStreak), 2);
+    assert_eq!(client.get_season_stats(&2, &alice).total_wins, 0);
+    assert!(client.get_season_leaderboard(&2, &LeaderboardKind::NetProfit, &10).is_empty());
+}
+
+#[test]
+fn test_unreached_tiers_not_paid() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+
+    client.start_season(&100_0000000, &vec![&env, 5_000, 3_000, 2_000], &LeaderboardKind::BestStreak);
+
+    // Only Alice has a streak, so only the first tier is paid
+    env.as_contract(&client.address, || {
+        VirtualTokenContract::_update_stats_win(&env, alice.clone(), &RoundMode::UpDown, 10, 20, None);
+    });
+
+    let before = client.balance(&alice);
+    assert_eq!(client.end_season(), 50_0000000);
+    assert_eq!(client.balance(&alice), before + 50_0000000);
+}
+
+#[test]
+fn test_prizes_follow_season_stats_not_cached_board() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.start_season(&100_0000000, &vec![&env, 7_000, 3_000], &LeaderboardKind::NetProfit);
+    play_round(&env, &client, &alice, &bob);
+
+    // A stale board that ranks Bob above Alice does not decide the prizes
+    let stale = vec![
+        &env,
+        LeaderboardEntry { user: bob.clone(), score: 900_0000000 },
+        LeaderboardEntry { user: alice.clone(), score: 0 },
+    ];
+    env.as_contract(&client.address, || {
+        let key = DataKey::Season(SeasonKey::Leaderboard(1, LeaderboardKind::NetProfit));
+        env.storage().persistent().set(&key, &stale);
+    });
+
+    let alice_before = client.balance(&alice);
+    let bob_before = client.balance(&bob);
+    assert_eq!(client.end_season(), 100_0000000);
+
+    assert_eq!(client.balance(&alice), alice_before + 70_0000000);
+    assert_eq!(client.balance(&bob), bob_before + 30_0000000);
+
+    // The stored board is replaced by the final ranking
+    let board = client.get_season_leaderboard(&1, &LeaderboardKind::NetProfit, &10);
+    assert_eq!(board.get(0).unwrap().user, alice);
+    assert_eq!(board.get(0).unwrap().score, client.get_season_stats(&1, &alice).net_pnl);
+    assert_eq!(board.get(1).unwrap().user, bob);
+}
@@ -0,0 +1,303 @@
+//! Tests for supply accounting: the breakdown counters and that they sum to the total supply.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::types::{BetSide, DataKey, LeaderboardKind, SupplyBreakdown, SweepDestination};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+const FLOOR: i128 = 1000_0000000;
+
+/// Returns the breakdown after checking that its parts sum to the total supply
+fn breakdown(client: &VirtualTokenContractClient) -> SupplyBreakdown {
+    let supply = client.get_supply_breakdown();
+    assert_eq!(
+        supply.circulating + supply.staked + supply.pending + supply.treasury + supply.jackpot
+            + supply.tournament_pools + supply.dust,
+        supply.total_supply
+    );
+    supply
+}
+
+#[test]
+fn test_breakdown_follows_a_round() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    assert_eq!(
+        breakdown(&client),
+        SupplyBreakdown {
+            total_supply: 3 * FLOOR,
+            circulating: 3 * FLOOR,
+            staked: 0,
+            pending: 0,
+            treasury: 0,
+            jackpot: 0,
+            tournament_pools: 0,
+            dust: 0,
+        }
+    );
+
+    client.set_fee_bps(&1_000);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.staked, 200_0000000);
+    assert_eq!(supply.circulating, 3 * FLOOR - 200_0000000);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.staked, 0);
+    assert_eq!(supply.pending, 190_0000000);
+    assert_eq!(supply.treasury, 10_0000000);
+    assert_eq!(supply.dust, 0);
+
+    client.claim_winnings(&alice);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.pending, 0);
+    assert_eq!(supply.circulating, 3 * FLOOR - 10_0000000);
+}
+
+#[test]
+fn test_payout_rounding_is_dust() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &200_0000000, &BetSide::Up);
+    client.place_bet(&dave, &100_0000001, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // The winners split 400.0000001 vXLM one-third and two-thirds
+    let supply = breakdown(&client);
+    assert_eq!(supply.dust, 1);
+    assert_eq!(supply.pending, 400_0000001 - 1);
+}
+
+#[test]
+fn test_sweep_moves_pending_into_jackpot_stake() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    client.set_claim_window(&10);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 11;
+    });
+    client.sweep_unclaimed(&1, &SweepDestination::Jackpot);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.pending, 0);
+    assert_eq!(supply.jackpot, 200_0000000);
+
+    // The next round carries the jackpot as part of its stake
+    client.create_round(&1_0000000, &None);
+    let supply = breakdown(&client);
+    assert_eq!(supply.jackpot, 0);
+    assert_eq!(supply.staked, 200_0000000);
+}
+
+#[test]
+fn test_replaced_round_stakes_become_dust() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    client.create_round(&1_0000000, &Some(1));
+    client.place_precision_prediction(&alice, &100_0000000, &1_2345);
+    assert_eq!(breakdown(&client).staked, 100_0000000);
+
+    client.create_round(&1_0000000, &None);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.staked, 0);
+    assert_eq!(supply.dust, 100_0000000);
+}
+
+#[test]
+fn test_tournament_entry_fees_are_held_until_finish() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    client.create_tournament(&100_0000000, &1_000, &1, &vec![&env, 10_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.tournament_pools, 200_0000000);
+
+    // Chips staked in a tournament round are not supply
+    client.create_tournament_round(&1, &1_0000000, &None);
+    client.place_bet(&alice, &1_000, &BetSide::Up);
+    client.place_bet(&bob, &1_000, &BetSide::Down);
+    assert_eq!(breakdown(&client).staked, 0);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+    client.finish_tournament(&1);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.tournament_pools, 0);
+    assert_eq!(supply.dust, 0);
+    assert_eq!(client.balance(&alice), FLOOR + 100_0000000);
+}
+
+#[test]
+fn test_season_prizes_add_to_supply() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    client.start_season(&500_0000000, &vec![&env, 10_000], &LeaderboardKind::NetProfit);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &100_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+    client.end_season();
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.total_supply, 3 * FLOOR + 500_0000000);
+    assert_eq!(client.balance(&alice), 900_0000000 + 500_0000000);
+}
+
+#[test]
+fn test_balances_from_before_tracking_are_not_counted() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    // A balance minted before the upgrade that added the supply counters
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::Balance(alice.clone()), &FLOOR);
+    });
+    assert_eq!(breakdown(&client).circulating, 0);
+
+    // Spending it still keeps the parts in step with the total, leaving circulating negative
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+
+    let supply = breakdown(&client);
+    assert_eq!(supply.total_supply, 0);
+    assert_eq!(supply.staked, 100_0000000);
+    assert_eq!(supply.circulating, -100_0000000);
+    assert_eq!(client.balance(&alice), FLOOR - 100_0000000);
+}
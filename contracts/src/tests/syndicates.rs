@@ -0,0 +1,322 @@
+//! Tests for syndicates: pooled deposits, captain bets and pro-rata payouts.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, SweepDestination};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+const FLOOR: i128 = 1000_0000000;
+
+/// Creates a syndicate in which Alice holds 300 vXLM of shares and Bob 100
+fn fund(client: &VirtualTokenContractClient, captain: &Address, alice: &Address, bob: &Address) -> u32 {
+    let syndicate_id = client.create_syndicate(captain);
+    client.deposit_syndicate(alice, &syndicate_id, &300_0000000);
+    client.deposit_syndicate(bob, &syndicate_id, &100_0000000);
+    syndicate_id
+}
+
+#[test]
+fn test_create_and_deposit() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let captain = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&captain);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    assert_eq!(client.create_syndicate(&captain), 1);
+    assert_eq!(client.deposit_syndicate(&alice, &1, &300_0000000), 300_0000000);
+    assert_eq!(client.deposit_syndicate(&bob, &1, &100_0000000), 100_0000000);
+    assert_eq!(client.deposit_syndicate(&alice, &1, &50_0000000), 50_0000000);
+
+    let syndicate = client.get_syndicate(&1).unwrap();
+    assert_eq!(syndicate.captain, captain);
+    assert_eq!(syndicate.members, vec![&env, alice.clone(), bob.clone()]);
+    assert_eq!(syndicate.total_shares, 450_0000000);
+    assert_eq!(syndicate.open_round, None);
+
+    assert_eq!(client.get_syndicate_shares(&1, &alice), 350_0000000);
+    assert_eq!(client.get_syndicate_value(&1), 450_0000000);
+    assert_eq!(client.balance(&syndicate.account), 450_0000000);
+    assert_eq!(client.balance(&alice), FLOOR - 350_0000000);
+
+    // Each syndicate bets from its own account
+    client.create_syndicate(&captain);
+    assert_ne!(client.get_syndicate(&2).unwrap().account, syndicate.account);
+}
+
+#[test]
+fn test_winnings_split_by_share() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let captain = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&captain);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    let syndicate_id = fund(&client, &captain, &alice, &bob);
+
+    client.create_round(&1_0000000, &None);
+    client.syndicate_bet(&syndicate_id, &400_0000000, &BetSide::Up);
+    client.place_bet(&dave, &400_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    assert_eq!(client.get_syndicate_value(&syndicate_id), 800_0000000);
+
+    assert_eq!(client.withdraw_syndicate(&alice, &syndicate_id, &300_0000000), 600_0000000);
+    assert_eq!(client.withdraw_syndicate(&bob, &syndicate_id, &100_0000000), 200_0000000);
+    assert_eq!(client.balance(&alice), FLOOR + 300_0000000);
+    assert_eq!(client.balance(&bob), FLOOR + 100_0000000);
+
+    let syndicate = client.get_syndicate(&syndicate_id).unwrap();
+    assert!(syndicate.members.is_empty());
+    assert_eq!(syndicate.total_shares, 0);
+    assert_eq!(client.balance(&syndicate.account), 0);
+}
+
+#[test]
+fn test_locked_while_pool_has_open_bet() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let captain = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&captain);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    let syndicate_id = fund(&client, &captain, &alice, &bob);
+
+    // An active round the pool has not bet on does not lock it
+    client.create_round(&1_0000000, &None);
+    client.deposit_syndicate(&bob, &syndicate_id, &100_0000000);
+
+    client.syndicate_bet(&syndicate_id, &200_0000000, &BetSide::Up);
+    client.place_bet(&dave, &200_0000000, &BetSide::Down);
+    assert_eq!(
+        client.try_withdraw_syndicate(&alice, &syndicate_id, &1),
+        Err(Ok(ContractError::SyndicateLocked))
+    );
+    assert_eq!(
+        client.try_deposit_syndicate(&alice, &syndicate_id, &1),
+        Err(Ok(ContractError::SyndicateLocked))
+    );
+
+    // Losing the bet leaves the rest of the pool to withdraw
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&5000000);
+    assert_eq!(client.get_syndicate_value(&syndicate_id), 300_0000000);
+    assert_eq!(client.withdraw_syndicate(&bob, &syndicate_id, &200_0000000), 120_0000000);
+}
+
+#[test]
+fn test_late_deposit_priced_at_pool_value() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let captain = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&captain);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    let syndicate_id = fund(&client, &captain, &alice, &bob);
+
+    client.create_round(&1_0000000, &None);
+    client.syndicate_bet(&syndicate_id, &400_0000000, &BetSide::Up);
+    client.place_bet(&dave, &400_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // The pool doubled, so 200 vXLM buys 100 shares and gains nothing from the earlier win
+    assert_eq!(client.deposit_syndicate(&dave, &syndicate_id, &200_0000000), 100_0000000);
+    assert_eq!(client.withdraw_syndicate(&dave, &syndicate_id, &100_0000000), 200_0000000);
+    assert_eq!(client.get_syndicate_value(&syndicate_id), 800_0000000);
+}
+
+#[test]
+fn test_wiped_out_pool_starts_over() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let captain = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&captain);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+
+    let syndicate_id = fund(&client, &captain, &alice, &bob);
+
+    client.create_round(&1_0000000, &None);
+    client.syndicate_bet(&syndicate_id, &400_0000000, &BetSide::Up);
+    client.place_bet(&dave, &400_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&5000000);
+
+    assert_eq!(client.deposit_syndicate(&dave, &syndicate_id, &100_0000000), 100_0000000);
+
+    let syndicate = client.get_syndicate(&syndicate_id).unwrap();
+    assert_eq!(syndicate.members, vec![&env, dave.clone()]);
+    assert_eq!(syndicate.total_shares, 100_0000000);
+    assert_eq!(client.get_syndicate_shares(&syndicate_id, &alice), 0);
+}
+
+#[test]
+fn test_invalid_syndicate_calls() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let captain = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&captain);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    let syndicate_id = fund(&client, &captain, &alice, &bob);
+
+    assert_eq!(client.try_deposit_syndicate(&alice, &9, &1), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.try_deposit_syndicate(&alice, &syndicate_id, &0), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(
+        client.try_deposit_syndicate(&alice, &syndicate_id, &FLOOR),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_withdraw_syndicate(&bob, &syndicate_id, &100_0000001),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_syndicate_bet(&syndicate_id, &100_0000000, &BetSide::Up),
+        Err(Ok(ContractError::NoActiveRound))
+    );
+
+    client.create_round(&1_0000000, &None);
+    assert_eq!(
+        client.try_syndicate_bet(&syndicate_id, &500_0000000, &BetSide::Up),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_pool_settled_before_claims_lapse() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let captain = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let dave = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&captain);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&dave);
+    client.set_claim_window(&10);
+
+    let syndicate_id = fund(&client, &captain, &alice, &bob);
+    let account = client.get_syndicate(&syndicate_id).unwrap().account;
+
+    client.create_round(&1_0000000, &None);
+    client.syndicate_bet(&syndicate_id, &400_0000000, &BetSide::Up);
+    client.place_bet(&dave, &400_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // The pool's winnings are claimed at resolution, so the sweep finds nothing of theirs
+    assert_eq!(client.balance(&account), 800_0000000);
+    assert_eq!(client.get_pending_winnings(&account), 0);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger + 11;
+    });
+    assert_eq!(client.sweep_unclaimed(&1, &SweepDestination::Treasury), 0);
+    assert_eq!(client.settle_syndicate(&syndicate_id), 800_0000000);
+    assert_eq!(client.get_syndicate_value(&syndicate_id), 800_0000000);
+
+    assert_eq!(client.try_settle_syndicate(&9), Err(Ok(ContractError::NotFound)));
+}
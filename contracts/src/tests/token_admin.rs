@@ -0,0 +1,144 @@
+//! Tests for admin minting, burning and airdrops.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::BetSide;
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+/// Asserts that the supply breakdown sums to the total supply
+fn assert_supply_sums(client: &VirtualTokenContractClient) {
+    let supply = client.get_supply_breakdown();
+    assert_eq!(
+        supply.circulating + supply.staked + supply.pending + supply.treasury + supply.jackpot
+            + supply.tournament_pools + supply.dust,
+        supply.total_supply
+    );
+}
+
+#[test]
+fn test_mint() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    assert_eq!(client.mint(&alice, &250_0000000), 250_0000000);
+    assert_eq!(client.mint(&alice, &50_0000000), 300_0000000);
+    assert_eq!(client.balance(&alice), 300_0000000);
+    assert_eq!(client.get_total_supply(), 300_0000000);
+    assert_supply_sums(&client);
+
+    assert_eq!(client.try_mint(&alice, &0), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(client.try_mint(&alice, &-1), Err(Ok(ContractError::InvalidParameters)));
+}
+
+#[test]
+fn test_burn() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.mint(&alice, &300_0000000);
+    assert_eq!(client.burn(&alice, &100_0000000), 200_0000000);
+    assert_eq!(client.get_total_supply(), 200_0000000);
+    assert_supply_sums(&client);
+
+    assert_eq!(client.try_burn(&alice, &200_0000001), Err(Ok(ContractError::InsufficientBalance)));
+    assert_eq!(client.try_burn(&alice, &0), Err(Ok(ContractError::InvalidParameters)));
+}
+
+#[test]
+fn test_burn_leaves_stakes_alone() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.mint(&alice, &300_0000000);
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &200_0000000, &BetSide::Up);
+
+    assert_eq!(client.try_burn(&alice, &150_0000000), Err(Ok(ContractError::InsufficientBalance)));
+    assert_eq!(client.burn(&alice, &100_0000000), 0);
+    assert_eq!(client.get_supply_breakdown().staked, 200_0000000);
+    assert_supply_sums(&client);
+}
+
+#[test]
+fn test_airdrop() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    let recipients = vec![
+        &env,
+        (alice.clone(), 100_0000000),
+        (bob.clone(), 50_0000000),
+        (alice.clone(), 10_0000000),
+    ];
+    assert_eq!(client.airdrop(&recipients), 160_0000000);
+    assert_eq!(client.balance(&alice), 110_0000000);
+    assert_eq!(client.balance(&bob), 50_0000000);
+    assert_eq!(client.get_total_supply(), 160_0000000);
+    assert_supply_sums(&client);
+}
+
+#[test]
+fn test_invalid_airdrop_mints_nothing() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    let recipients = vec![&env, (alice.clone(), 100_0000000), (bob.clone(), 0)];
+    assert_eq!(client.try_airdrop(&recipients), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(client.try_airdrop(&vec![&env]), Err(Ok(ContractError::InvalidParameters)));
+
+    let mut too_many = vec![&env];
+    for _ in 0..101 {
+        too_many.push_back((alice.clone(), 1));
+    }
+    assert_eq!(client.try_airdrop(&too_many), Err(Ok(ContractError::InvalidParameters)));
+
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.get_total_supply(), 0);
+}
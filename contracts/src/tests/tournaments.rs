@@ -0,0 +1,456 @@
+//! Tests for tournaments: entry fees, chip balances, elimination and prizes.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::errors::ContractError;
+use crate::types::{BetSide, PenaltyDestination, TournamentStatus};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, vec, Address, Env};
+
+const FEE: i128 = 100_0000000;
+const CHIPS: i128 = 1_000;
+
+#[test]
+fn test_create_tournament() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    let tiers = vec![&env, 7_000, 3_000];
+    assert_eq!(client.create_tournament(&FEE, &CHIPS, &3, &tiers), 1);
+    assert_eq!(client.create_tournament(&0, &CHIPS, &1, &vec![&env, 10_000]), 2);
+
+    let tournament = client.get_tournament(&1).unwrap();
+    assert_eq!(tournament.entry_fee, FEE);
+    assert_eq!(tournament.starting_chips, CHIPS);
+    assert_eq!(tournament.total_rounds, 3);
+    assert_eq!(tournament.rounds_played, 0);
+    assert_eq!(tournament.prize_tiers, tiers);
+    assert_eq!(tournament.prize_pool, 0);
+    assert_eq!(tournament.status, TournamentStatus::Registration);
+    assert_eq!(client.get_tournament(&3), None);
+
+    let tiers = vec![&env, 10_000];
+    assert_eq!(client.try_create_tournament(&-1, &CHIPS, &3, &tiers), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(client.try_create_tournament(&FEE, &0, &3, &tiers), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(client.try_create_tournament(&FEE, &CHIPS, &0, &tiers), Err(Ok(ContractError::InvalidParameters)));
+    assert_eq!(
+        client.try_create_tournament(&FEE, &CHIPS, &3, &vec![&env, 6_000, 3_000]),
+        Err(Ok(ContractError::InvalidPrizes))
+    );
+}
+
+#[test]
+fn test_join_tournament() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_tournament(&FEE, &CHIPS, &2, &vec![&env, 10_000]);
+    client.join_tournament(&alice, &1);
+
+    assert_eq!(client.balance(&alice), 1000_0000000 - FEE);
+    assert_eq!(client.get_tournament_chips(&1, &alice), CHIPS);
+    assert_eq!(client.get_tournament_chips(&1, &bob), 0);
+
+    let tournament = client.get_tournament(&1).unwrap();
+    assert_eq!(tournament.prize_pool, FEE);
+    assert_eq!(tournament.players, vec![&env, alice.clone()]);
+
+    assert_eq!(client.try_join_tournament(&alice, &1), Err(Ok(ContractError::AlreadyRegistered)));
+    assert_eq!(client.try_join_tournament(&alice, &2), Err(Ok(ContractError::NotFound)));
+
+    let broke = Address::generate(&env);
+    assert_eq!(client.try_join_tournament(&broke, &1), Err(Ok(ContractError::InsufficientBalance)));
+
+    // The first tournament round closes registration
+    client.create_tournament_round(&1, &1_0000000, &None);
+    assert_eq!(client.get_tournament(&1).unwrap().status, TournamentStatus::Running);
+    assert_eq!(client.try_join_tournament(&bob, &1), Err(Ok(ContractError::RegistrationClosed)));
+}
+
+#[test]
+fn test_tournament_round_uses_chips() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&carol);
+
+    client.set_fee_bps(&1_000);
+    client.create_tournament(&FEE, &CHIPS, &2, &vec![&env, 10_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+
+    client.create_tournament_round(&1, &1_0000000, &None);
+    assert_eq!(client.get_active_round().unwrap().tournament_id, Some(1));
+
+    client.place_bet(&alice, &600, &BetSide::Up);
+    client.place_bet(&bob, &400, &BetSide::Down);
+    assert_eq!(client.get_tournament_chips(&1, &alice), 400);
+    assert_eq!(client.balance(&alice), 1000_0000000 - FEE);
+
+    // Non-players have no chips to stake
+    assert_eq!(
+        client.try_place_bet(&carol, &100, &BetSide::Up),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // Winnings go straight back to chips, with no fee, claim or stats
+    assert_eq!(client.get_tournament_chips(&1, &alice), 1_400);
+    assert_eq!(client.get_tournament_chips(&1, &bob), 600);
+    assert_eq!(client.get_pending_winnings(&alice), 0);
+    assert_eq!(client.get_treasury(), 0);
+    assert_eq!(client.get_user_stats(&alice).rounds_played, 0);
+    assert_eq!(client.balance(&alice), 1000_0000000 - FEE);
+    assert_eq!(client.get_tournament(&1).unwrap().rounds_played, 1);
+
+    // Regular rounds go back to vXLM balances
+    client.create_round(&1_0000000, &None);
+    assert_eq!(client.get_active_round().unwrap().tournament_id, None);
+    client.place_bet(&alice, &10_0000000, &BetSide::Up);
+    assert_eq!(client.balance(&alice), 1000_0000000 - FEE - 10_0000000);
+    assert_eq!(client.get_tournament_chips(&1, &alice), 1_400);
+}
+
+#[test]
+fn test_tournament_round_cannot_be_replaced() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_tournament(&FEE, &CHIPS, &2, &vec![&env, 10_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+    client.create_tournament_round(&1, &1_0000000, &None);
+    client.place_bet(&alice, &600, &BetSide::Up);
+
+    assert_eq!(client.try_create_round(&1_0000000, &None), Err(Ok(ContractError::TournamentNotOver)));
+    assert_eq!(
+        client.try_create_tournament_round(&1, &1_0000000, &None),
+        Err(Ok(ContractError::TournamentNotOver))
+    );
+    assert_eq!(client.get_active_round().unwrap().tournament_id, Some(1));
+    assert_eq!(client.get_tournament_chips(&1, &alice), 400);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+    client.create_round(&1_0000000, &None);
+}
+
+#[test]
+fn test_tournament_round_needs_players() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+
+    client.create_tournament(&FEE, &CHIPS, &2, &vec![&env, 10_000]);
+    assert_eq!(
+        client.try_create_tournament_round(&1, &1_0000000, &None),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+    assert_eq!(client.get_tournament(&1).unwrap().status, TournamentStatus::Registration);
+    assert_eq!(client.get_active_round(), None);
+}
+
+#[test]
+fn test_tournament_round_keeps_open_stakes() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_tournament(&FEE, &CHIPS, &2, &vec![&env, 10_000]);
+    client.join_tournament(&alice, &1);
+
+    // A regular round with stakes must resolve before a tournament round replaces it
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&bob, &10_0000000, &BetSide::Up);
+    assert_eq!(
+        client.try_create_tournament_round(&1, &1_0000000, &None),
+        Err(Ok(ContractError::RoundNotEnded))
+    );
+    assert_eq!(client.get_active_round().unwrap().tournament_id, None);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+    client.create_tournament_round(&1, &1_0000000, &None);
+    assert_eq!(client.get_active_round().unwrap().tournament_id, Some(1));
+
+    // An empty regular round holds nothing and can still be replaced
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_0000000);
+    client.create_round(&1_0000000, &None);
+    client.create_tournament_round(&1, &1_0000000, &None);
+    assert_eq!(client.get_active_round().unwrap().tournament_id, Some(1));
+}
+
+#[test]
+fn test_tournament_jackpot_isolated() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.set_exit_penalty(&1_000, &PenaltyDestination::Treasury);
+    client.create_tournament(&FEE, &CHIPS, &2, &vec![&env, 10_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+
+    // The exit penalty stays in the pot, and the one-sided round rolls it into the tournament
+    client.create_tournament_round(&1, &1_0000000, &None);
+    client.place_bet(&alice, &500, &BetSide::Up);
+    assert_eq!(client.withdraw_bet(&alice, &1, &200), 180);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    assert_eq!(client.get_treasury(), 0);
+    assert_eq!(client.get_jackpot(), 0);
+    assert_eq!(client.get_tournament_chips(&1, &alice), 980);
+    assert_eq!(client.get_tournament(&1).unwrap().jackpot, 20);
+
+    // The next tournament round pays it out
+    client.create_tournament_round(&1, &1_0000000, &None);
+    assert_eq!(client.get_active_round().unwrap().jackpot, 20);
+    assert_eq!(client.get_tournament(&1).unwrap().jackpot, 0);
+}
+
+#[test]
+fn test_finish_tournament_pays_prizes() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&carol);
+
+    client.create_tournament(&FEE, &CHIPS, &1, &vec![&env, 7_000, 3_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+    client.join_tournament(&carol, &1);
+
+    client.create_tournament_round(&1, &1_0000000, &None);
+    assert_eq!(client.try_finish_tournament(&1), Err(Ok(ContractError::TournamentNotOver)));
+
+    client.place_bet(&alice, &500, &BetSide::Down);
+    client.place_bet(&bob, &300, &BetSide::Up);
+    client.place_bet(&carol, &100, &BetSide::Up);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    assert_eq!(
+        client.try_create_tournament_round(&1, &1_0000000, &None),
+        Err(Ok(ContractError::TournamentOver))
+    );
+
+    let standings = client.finish_tournament(&1);
+    assert_eq!(standings.len(), 3);
+
+    let first = standings.get(0).unwrap();
+    assert_eq!((first.user, first.chips, first.rank), (bob.clone(), 1_375, 1));
+    assert_eq!(first.prize, 210_0000000);
+
+    let second = standings.get(1).unwrap();
+    assert_eq!((second.user, second.chips, second.rank), (carol.clone(), 1_125, 2));
+    assert_eq!(second.prize, 90_0000000);
+
+    let third = standings.get(2).unwrap();
+    assert_eq!((third.user, third.chips, third.rank, third.prize), (alice.clone(), 500, 3, 0));
+
+    assert_eq!(client.balance(&bob), 1000_0000000 - FEE + 210_0000000);
+    assert_eq!(client.balance(&carol), 1000_0000000 - FEE + 90_0000000);
+    assert_eq!(client.balance(&alice), 1000_0000000 - FEE);
+
+    assert_eq!(client.get_tournament(&1).unwrap().status, TournamentStatus::Finished);
+    assert_eq!(client.get_tournament_standings(&1), standings);
+    assert_eq!(client.try_finish_tournament(&1), Err(Ok(ContractError::TournamentOver)));
+}
+
+#[test]
+fn test_last_player_standing_finishes_early() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_tournament(&FEE, &CHIPS, &5, &vec![&env, 6_000, 4_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+    assert_eq!(client.try_finish_tournament(&1), Err(Ok(ContractError::TournamentNotOver)));
+
+    client.create_tournament_round(&1, &1_0000000, &None);
+    client.place_bet(&alice, &CHIPS, &BetSide::Up);
+    client.place_bet(&bob, &CHIPS, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // Bob is eliminated: no chips left to bet with
+    let standings = client.get_tournament_standings(&1);
+    assert!(!standings.get(0).unwrap().eliminated);
+    assert!(standings.get(1).unwrap().eliminated);
+
+    // The sole survivor takes the whole pool
+    let standings = client.finish_tournament(&1);
+    assert_eq!(standings.get(0).unwrap().prize, 2 * FEE);
+    assert_eq!(standings.get(1).unwrap().prize, 0);
+    assert_eq!(client.balance(&alice), 1000_0000000 + FEE);
+    assert_eq!(client.balance(&bob), 1000_0000000 - FEE);
+}
+
+#[test]
+fn test_tied_players_split_tiers() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+    client.mint_initial(&carol);
+
+    client.create_tournament(&FEE, &CHIPS, &1, &vec![&env, 5_000, 3_000, 2_000]);
+    client.join_tournament(&alice, &1);
+    client.join_tournament(&bob, &1);
+    client.join_tournament(&carol, &1);
+
+    client.create_tournament_round(&1, &1_0000000, &None);
+    client.place_bet(&alice, &200, &BetSide::Up);
+    client.place_bet(&bob, &200, &BetSide::Up);
+    client.place_bet(&carol, &400, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // Alice and Bob share first place and split the top two tiers
+    let standings = client.finish_tournament(&1);
+    assert_eq!(standings.get(0).unwrap().rank, 1);
+    assert_eq!(standings.get(1).unwrap().rank, 1);
+    assert_eq!(standings.get(2).unwrap().rank, 3);
+    assert_eq!(standings.get(0).unwrap().prize, 120_0000000);
+    assert_eq!(standings.get(1).unwrap().prize, 120_0000000);
+    assert_eq!(standings.get(2).unwrap().prize, 60_0000000);
+    assert_eq!(client.balance(&alice), client.balance(&bob));
+}
@@ -0,0 +1,217 @@
+//! Tests for per-user volume, P&L and per-mode statistics.
+
+use crate::contract::{VirtualTokenContract, VirtualTokenContractClient};
+use crate::types::{BetSide, DataKey, LegacyUserStats};
+use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env};
+
+#[test]
+fn test_updown_volume_and_pnl() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &50_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    let alice_stats = client.get_user_stats(&alice);
+    assert_eq!(alice_stats.rounds_played, 1);
+    assert_eq!(alice_stats.total_staked, 100_0000000);
+    assert_eq!(alice_stats.total_won, 150_0000000);
+    assert_eq!(alice_stats.net_pnl, 50_0000000);
+    assert_eq!(alice_stats.roi_bps, 5_000);
+    assert_eq!(alice_stats.biggest_win, 50_0000000);
+    assert_eq!(alice_stats.updown_wins, 1);
+
+    let bob_stats = client.get_user_stats(&bob);
+    assert_eq!(bob_stats.total_staked, 50_0000000);
+    assert_eq!(bob_stats.total_won, 0);
+    assert_eq!(bob_stats.net_pnl, -50_0000000);
+    assert_eq!(bob_stats.roi_bps, -10_000);
+    assert_eq!(bob_stats.updown_losses, 1);
+}
+
+#[test]
+fn test_refunds_counted_separately() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &50_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_0000000);
+
+    let stats = client.get_user_stats(&alice);
+    assert_eq!(stats.rounds_played, 1);
+    assert_eq!(stats.refunds, 1);
+    assert_eq!(stats.total_wins + stats.total_losses, 0);
+    assert_eq!(stats.total_staked, 0);
+    assert_eq!(stats.net_pnl, 0);
+}
+
+#[test]
+fn test_precision_accuracy() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&2297, &Some(1));
+    client.place_precision_prediction(&alice, &10_0000000, &2303);
+    client.place_precision_prediction(&bob, &10_0000000, &2350);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    client.create_round(&2300, &Some(1));
+    client.place_precision_prediction(&alice, &10_0000000, &2290);
+    client.place_precision_prediction(&bob, &10_0000000, &2301);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&2300);
+
+    // Errors of 3 and 10 average to 6 (floored)
+    let stats = client.get_user_stats(&alice);
+    assert_eq!(stats.precision_wins, 1);
+    assert_eq!(stats.precision_losses, 1);
+    assert_eq!(stats.precision_total_error, 13);
+    assert_eq!(stats.precision_avg_error, 6);
+    assert_eq!(stats.net_pnl, 0);
+    assert_eq!(stats.biggest_win, 10_0000000);
+    assert_eq!(stats.updown_wins + stats.updown_losses, 0);
+}
+
+#[test]
+fn test_buckets_counters() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    client.create_round(&1_0000000, &Some(2));
+    client.place_bucket_bet(&alice, &20_0000000, &4);
+    client.place_bucket_bet(&bob, &20_0000000, &0);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_0500000);
+
+    assert_eq!(client.get_user_stats(&alice).buckets_wins, 1);
+    assert_eq!(client.get_user_stats(&bob).buckets_losses, 1);
+    assert_eq!(client.get_user_stats(&alice).net_pnl, 20_0000000);
+}
+
+#[test]
+fn test_legacy_stats_migrated() {
+    let env = Env::default();
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &oracle);
+    client.mint_initial(&alice);
+    client.mint_initial(&bob);
+
+    env.as_contract(&client.address, || {
+        let legacy = LegacyUserStats { total_wins: 3, total_losses: 1, current_streak: 2, best_streak: 3 };
+        env.storage().persistent().set(&DataKey::UserStats(alice.clone()), &legacy);
+    });
+
+    // Reads convert on the fly, keeping the old counters
+    let stats = client.get_user_stats(&alice);
+    assert_eq!(stats.total_wins, 3);
+    assert_eq!(stats.total_losses, 1);
+    assert_eq!(stats.best_streak, 3);
+    assert_eq!(stats.rounds_played, 4);
+    assert_eq!(stats.total_staked, 0);
+
+    client.create_round(&1_0000000, &None);
+    client.place_bet(&alice, &100_0000000, &BetSide::Up);
+    client.place_bet(&bob, &50_0000000, &BetSide::Down);
+
+    let end_ledger = client.get_active_round().unwrap().end_ledger;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = end_ledger;
+    });
+    client.resolve_round(&1_5000000);
+
+    // The first update writes the new layout and drops the legacy entry
+    let stats = client.get_user_stats(&alice);
+    assert_eq!(stats.total_wins, 4);
+    assert_eq!(stats.current_streak, 3);
+    assert_eq!(stats.best_streak, 3);
+    assert_eq!(stats.rounds_played, 5);
+    assert_eq!(stats.net_pnl, 50_0000000);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&DataKey::UserStats(alice.clone())));
+        assert!(env.storage().persistent().has(&DataKey::UserStatsV2(alice.clone())));
+    });
+}
@@ -1,6 +1,6 @@
 //! Type definitions for the XLM Price Prediction Market.
 
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
 /// Round mode for prediction type
 #[contracttype]
@@ -9,6 +9,7 @@ use soroban_sdk::{contracttype, Address};
 pub enum RoundMode {
     UpDown = 0,     // Simple up/down predictions
     Precision = 1,  // Exact price predictions (Legends mode)
+    Buckets = 2,    // Stake on a range of price change relative to price_start
 }
 
 /// Storage keys for contract data
@@ -23,9 +24,147 @@ pub enum DataKey {
     UpDownPositions,      // Map<Address, i128> for Up/Down mode
     PrecisionPositions,   // Vec<PrecisionPrediction> for Precision mode
     PendingWinnings(Address),
-    UserStats(Address),
+    UserStats(Address),   // LegacyUserStats written before per-round volume tracking; migrated on first update
     BetWindowLedgers,     // Bet window duration in ledgers
     RunWindowLedgers,     // Run window duration in ledgers
+    FeeBps,               // Protocol fee in basis points, taken from the losing side
+    Treasury,             // Accumulated protocol fees
+    RoundCounter,         // Id of the most recently created round
+    RoundWinnings(Address, u32),  // Unclaimed payout for a user in a specific round
+    ClaimableRounds(Address),     // Vec<u32> of round ids with unclaimed payouts, oldest first
+    ClaimWindowLedgers,   // Ledgers after resolution before claims lapse (unset = no deadline)
+    ClaimDeadline(u32),   // Last ledger a round's winnings can be claimed
+    RoundClaimants(u32),  // Vec<Address> credited in a round with a claim deadline
+    ExpiringRounds,       // Vec<u32> of rounds with a deadline that have not been swept
+    Jackpot,              // Swept funds carried into the next round's payout
+    ExitPenaltyBps,       // Penalty in basis points for withdrawing a bet early
+    ExitPenaltyDestination, // PenaltyDestination for early-exit penalties
+    BetLimits(RoundMode), // Stake limits for rounds of a given mode
+    BucketBounds,         // Vec<i32> bucket boundaries in bps used by new Buckets rounds
+    BucketPositions,      // Map<Address, BucketPosition> for Buckets mode
+    PrecisionPayout,      // PrecisionPayout policy used by new Precision rounds
+    RoundRanks(u32),      // Vec<PrecisionRank> for a resolved Precision round, best first
+    RevealWindowLedgers,  // Ledgers after betting closes during which sealed predictions can be revealed
+    PrecisionCommits,     // Map<Address, PrecisionCommit> of sealed, unrevealed predictions
+    PrecisionTieRules,    // PrecisionTieRules used by new Precision rounds
+    RoundResult(u32),     // RoundResult recorded when a round is resolved
+    UserStatsV2(Address), // UserStats with volume, P&L and per-mode counters
+    Leaderboard(LeaderboardKind),  // Vec<LeaderboardEntry>, best first, at most LEADERBOARD_CANDIDATES entries
+    TournamentCounter,    // Last tournament id issued
+    Tournament(u32),      // Tournament settings, players and progress
+    TournamentChips(u32, Address),  // A player's chip balance in a tournament
+    TournamentStandings(u32),       // Vec<TournamentStanding> recorded when a tournament finishes
+    ActiveTournamentRound,  // Id of the tournament the active round belongs to, absent for regular rounds
+    Achievements(Address),  // Vec<Achievement> earned by a user, oldest first
+    ReferralBps,          // Share of the protocol fee on a referred user's winnings paid to their referrer
+    Referrer(Address),    // Address that referred a user
+    ReferralStats(Address),  // ReferralStats for a referrer
+    Token(TokenKey),      // vXLM supply and faucet data
+    Syndicate(SyndicateKey), // Syndicates and members' shares
+    Season(SeasonKey),    // Seasons, their stats and leaderboards
+}
+
+/// Storage keys for vXLM supply and the faucet, nested under `DataKey::Token`
+/// (the contract spec allows at most 50 `DataKey` variants)
+#[contracttype]
+#[derive(Clone)]
+pub enum TokenKey {
+    TotalSupply,          // vXLM minted less burned since tracking began
+    FaucetConfig,         // FaucetConfig for refills
+    FaucetClaim(Address), // A user's last faucet refill
+    FaucetEmission,       // FaucetEmission for the current faucet day
+    Circulating,          // Sum of all user balances
+    Pending,              // Sum of all pending winnings
+    TournamentPools,      // Entry fees held by unfinished tournaments
+    Dust,                 // vXLM no longer held anywhere (payout rounding, discarded rounds)
+}
+
+/// Storage keys for syndicates, nested under `DataKey::Syndicate`
+#[contracttype]
+#[derive(Clone)]
+pub enum SyndicateKey {
+    Counter,              // Last syndicate id
+    Info(u32),            // Syndicate by id
+    Shares(u32, Address), // A member's shares in a syndicate
+    Betting,              // Vec<u32> of syndicates with a bet on the active round, settled when it resolves
+}
+
+/// Storage keys for seasons, nested under `DataKey::Season`
+#[contracttype]
+#[derive(Clone)]
+pub enum SeasonKey {
+    Counter,                          // Last season id issued
+    Active,                           // Id of the running season, absent between seasons
+    Info(u32),                        // Season settings and result
+    Stats(u32, Address),              // UserStats accumulated during a season
+    Leaderboard(u32, LeaderboardKind), // Leaderboard built from season stats
+    Participants(u32),                // Vec<Address> of users with season stats, in order of first activity
+}
+
+/// Where unclaimed winnings go when a lapsed round is swept
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SweepDestination {
+    Treasury,
+    Jackpot,
+}
+
+/// Stake limits for one round mode (0 = no limit)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BetLimits {
+    pub min_bet: i128,          // Smallest opening stake
+    pub max_bet_per_user: i128, // Largest total stake a user may hold in one round
+    pub max_pool_size: i128,    // Largest total stake across all users in one round
+}
+
+/// Projected outcome of a new Up/Down stake if its side wins
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BetQuote {
+    pub payout: i128,          // Stake plus winnings
+    pub net_winnings: i128,    // Payout minus stake
+    pub multiplier_bps: i128,  // Payout per unit staked, in basis points (10_000 = 1x)
+}
+
+/// Current Up/Down pools and the payout multiplier each side would get
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundOdds {
+    pub round_id: u32,
+    pub pool_up: i128,
+    pub pool_down: i128,
+    pub up_multiplier_bps: i128,    // 0 while nobody has bet UP
+    pub down_multiplier_bps: i128,  // 0 while nobody has bet DOWN
+}
+
+/// Snapshot of the admin-configurable settings
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractConfig {
+    pub bet_ledgers: u32,
+    pub run_ledgers: u32,
+    pub fee_bps: u32,
+    pub exit_penalty_bps: u32,
+    pub exit_penalty_destination: PenaltyDestination,
+    pub claim_window: Option<u32>,
+    pub reveal_ledgers: Option<u32>,
+    pub updown_limits: BetLimits,
+    pub precision_limits: BetLimits,
+    pub buckets_limits: BetLimits,
+    pub bucket_bounds: Vec<i32>,
+    pub precision_payout: PrecisionPayout,
+    pub precision_tie_rules: PrecisionTieRules,
+    pub referral_bps: u32,
+    pub faucet: FaucetConfig,
+}
+
+/// Where early-exit penalties go when a bet is withdrawn
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PenaltyDestination {
+    Pool,      // Stays in the round and is shared by its winners
+    Treasury,  // Credited to the protocol treasury
 }
 
 /// Represents which side a user bet on
@@ -43,6 +182,7 @@ pub struct UserPosition {
     pub side: BetSide,
 }
 
+/// Per-user results across settled rounds
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct UserStats {
@@ -50,6 +190,265 @@ pub struct UserStats {
     pub total_losses: u32,
     pub current_streak: u32,
     pub best_streak: u32,
+    pub rounds_played: u32,            // Rounds taken part in, including refunded ones
+    pub refunds: u32,                  // Rounds whose stake was returned (unchanged price or voided)
+    pub total_staked: i128,            // Stakes in settled rounds
+    pub total_won: i128,               // Payouts from winning rounds, stake included
+    pub net_pnl: i128,                 // Payouts minus stakes across settled rounds
+    pub roi_bps: i128,                 // net_pnl / total_staked in basis points
+    pub biggest_win: i128,             // Largest profit from a single round
+    pub updown_wins: u32,
+    pub updown_losses: u32,
+    pub precision_wins: u32,
+    pub precision_losses: u32,
+    pub buckets_wins: u32,
+    pub buckets_losses: u32,
+    pub precision_total_error: u128,   // Sum of absolute errors across settled Precision rounds
+    pub precision_avg_error: u128,     // precision_total_error / settled Precision rounds
+}
+
+/// Metric a leaderboard ranks users by
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LeaderboardKind {
+    NetProfit,          // Highest net_pnl first
+    BestStreak,         // Longest best_streak first
+    PrecisionAccuracy,  // Lowest precision_avg_error first, once enough Precision rounds are settled
+}
+
+/// A user's place on a leaderboard
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaderboardEntry {
+    pub user: Address,
+    pub score: i128,  // Value of the leaderboard's metric for this user
+}
+
+/// Competition period with its own stats, leaderboards and prize pool
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Season {
+    pub season_id: u32,            // Sequential season identifier (starts at 1)
+    pub start_ledger: u32,
+    pub end_ledger: Option<u32>,   // Set when the season ends
+    pub prize_pool: i128,          // vXLM paid to the top of the ranking leaderboard at season end
+    pub prize_tiers: Vec<u32>,     // Share of the prize pool in bps for each leaderboard position
+    pub ranking: LeaderboardKind,  // Leaderboard that decides the prizes
+    pub prizes_paid: i128,         // Total paid at season end; tiers nobody reached are not paid
+}
+
+/// Faucet refill rules (caps of 0 = no cap)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaucetConfig {
+    pub floor: i128,             // Balance a refill tops the user up to
+    pub cooldown_ledgers: u32,   // Ledgers between a user's refills
+    pub user_daily_cap: i128,    // Most vXLM one address can receive per faucet day
+    pub global_daily_cap: i128,  // Most vXLM the faucet emits per faucet day
+}
+
+/// A user's most recent faucet refill
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaucetClaim {
+    pub last_ledger: u32,     // Ledger of the last refill
+    pub day: u32,             // Faucet day of the last refill (ledger / LEDGERS_PER_DAY)
+    pub claimed_today: i128,  // Received during that faucet day
+}
+
+/// vXLM emitted by the faucet during one faucet day
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaucetEmission {
+    pub day: u32,
+    pub emitted: i128,
+}
+
+/// Where the vXLM supply currently sits; every field but `total_supply` sums to it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupplyBreakdown {
+    pub total_supply: i128,     // vXLM minted less burned since tracking began
+    pub circulating: i128,      // User balances, counted since tracking began
+    pub staked: i128,           // Stakes and jackpot of the active round (tournament chips excluded)
+    pub pending: i128,          // Unclaimed winnings and referral rewards
+    pub treasury: i128,         // Collected fees
+    pub jackpot: i128,          // Jackpot waiting for the next round
+    pub tournament_pools: i128, // Entry fees of unfinished tournaments
+    pub dust: i128,             // Lost to payout rounding or discarded with an overwritten round
+}
+
+/// What a referrer has earned from the users they referred
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferralStats {
+    pub referred_users: u32,  // Users registered with this referrer
+    pub total_rewards: i128,  // Fee shares credited to pending winnings
+}
+
+/// Milestone a user can earn a badge for
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AchievementKind {
+    FirstWin,   // First winning round
+    Streak5,    // Best streak reached 5 wins
+    Streak10,   // Best streak reached 10 wins
+    ExactHit,   // Precision prediction matched the final price exactly
+    Rounds100,  // 100 rounds played, refunds included
+}
+
+/// Non-transferable badge held by a user
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Achievement {
+    pub kind: AchievementKind,
+    pub earned_ledger: u32,  // Ledger the milestone was reached on
+}
+
+/// Lifecycle of a tournament
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TournamentStatus {
+    Registration,  // Accepting players; closes when the first tournament round opens
+    Running,       // Rounds are being played
+    Finished,      // Prizes paid
+}
+
+/// Fixed sequence of rounds played with chips bought by an entry fee
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tournament {
+    pub tournament_id: u32,        // Sequential tournament identifier (starts at 1)
+    pub entry_fee: i128,           // vXLM debited from a player's balance on joining
+    pub starting_chips: i128,      // Chips each player starts with
+    pub total_rounds: u32,         // Rounds played before the tournament can finish
+    pub rounds_played: u32,        // Tournament rounds resolved so far
+    pub prize_tiers: Vec<u32>,     // Share of the prize pool in bps for each final position
+    pub prize_pool: i128,          // Entry fees collected, paid out in vXLM at the finish
+    pub jackpot: i128,             // Chips nobody won, carried into the next tournament round
+    pub players: Vec<Address>,     // In joining order
+    pub status: TournamentStatus,
+}
+
+/// A player's place in a tournament, ranked by chips
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TournamentStanding {
+    pub user: Address,
+    pub chips: i128,
+    pub rank: u32,         // 1 = most chips; equal chips share a rank
+    pub eliminated: bool,  // Out of chips: can no longer bet and wins no prize
+    pub prize: i128,       // vXLM won, set when the tournament finishes
+}
+
+/// A shared betting pool: members deposit vXLM for shares and the captain bets it for them
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Syndicate {
+    pub syndicate_id: u32,
+    pub captain: Address,
+    pub account: Address,         // Holds the pool's vXLM and bets; nobody can sign for it
+    pub members: Vec<Address>,    // Members holding shares, in joining order
+    pub total_shares: i128,
+    pub open_round: Option<u32>,  // Round the pool last bet on
+}
+
+/// UserStats as stored before volume and per-mode tracking
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyUserStats {
+    pub total_wins: u32,
+    pub total_losses: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+}
+
+/// How a Precision round's pot is shared among the closest guesses
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PrecisionPayout {
+    Even,                           // Winners split the pot evenly regardless of stake (default)
+    StakeWeighted(StakeWeighting),  // Winners share in proportion to stake, capped per unit staked
+    Tiered(Vec<u32>),               // Pot split by rank using a table of bps (e.g. 6000, 2500, 1500)
+    Scored(Scoring),                // Pot split by stake times a score that falls off with error
+}
+
+/// Parameters for error-scored Precision payouts
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scoring {
+    pub kernel: ScoringKernel,
+    pub width: u128,  // Error (4-decimal price units) at which the kernel's falloff is measured
+}
+
+/// Score as a function of a prediction's absolute error
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScoringKernel {
+    InverseDistance,  // width / (width + error): half score at error == width
+    Gaussian,         // exp(-error^2 / (2 * width^2)): ~0.61 at error == width, 0 beyond 10 widths
+}
+
+/// Near-tie handling and exact-hit bonus for Precision rounds
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrecisionTieRules {
+    pub tolerance: Tolerance,        // Band around the closest guess that also counts as first place
+    pub bonus_multiplier_bps: u32,   // Exact hits earn up to stake * bonus_multiplier_bps / 10_000 extra
+    pub bonus_pool_bps: u32,         // Share of the pot (after fee) set aside to fund exact-hit bonuses
+}
+
+/// Tolerance band within which a prediction ties the closest guess
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tolerance {
+    Exact,        // Only identical errors tie (default)
+    Units(u128),  // Errors within this many 4-decimal price units of the closest guess
+    Bps(u32),     // Errors within this many bps of the final price of the closest guess
+}
+
+/// Parameters for stake-weighted Precision payouts
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeWeighting {
+    pub max_win_bps: u32,             // Most a winner can win per unit staked (10_000 = stake matched 1:1)
+    pub remainder: RemainderPolicy,   // What happens to losers' stakes the winners cannot claim
+}
+
+/// What happens to pot left over after capped payouts
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RemainderPolicy {
+    Refund,    // Returned to losers in proportion to stake
+    Rollover,  // Added to the jackpot for the next round
+}
+
+/// Sealed Precision prediction awaiting reveal
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrecisionCommit {
+    pub hash: BytesN<32>,  // sha256(user XDR || predicted_price as 16 big-endian bytes || salt)
+    pub amount: i128,      // Stake, forfeited if never revealed
+}
+
+/// A prediction's standing in a resolved Precision round
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrecisionRank {
+    pub user: Address,
+    pub predicted_price: u128,
+    pub amount: i128,    // Stake
+    pub error: u128,     // Absolute distance from the final price
+    pub rank: u32,       // 1 = closest; tied predictions share a rank and the next rank is skipped
+    pub payout: i128,    // Amount credited to the user for this round
+}
+
+/// Stake on a price-change bucket (Buckets mode)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BucketPosition {
+    pub amount: i128,
+    pub bucket: u32,  // Index into the round's buckets, lowest range first
 }
 
 /// Precision prediction entry (user address + predicted price)
@@ -61,15 +460,62 @@ pub struct PrecisionPrediction {
     pub amount: i128,           // Bet amount
 }
 
+/// How a resolved round was settled
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RoundOutcome {
+    Settled,   // Winners were paid from the losing stakes
+    Refunded,  // Price unchanged: every stake returned
+    Voided,    // One-sided round or empty winning side/bucket: every stake returned, no stats change
+    Empty,     // Nobody took part
+}
+
+/// Result of a resolved round
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundResult {
+    pub round_id: u32,
+    pub mode: RoundMode,
+    pub price_start: u128,
+    pub final_price: u128,
+    pub outcome: RoundOutcome,
+    pub resolved_ledger: u32,
+}
+
+/// Unclaimed payout that lapses at `deadline_ledger`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpiringClaim {
+    pub round_id: u32,
+    pub amount: i128,
+    pub deadline_ledger: u32,
+}
+
+/// Unclaimed payout attributed to a single round
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundClaim {
+    pub round_id: u32,
+    pub amount: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Round {
+    pub round_id: u32,       // Sequential round identifier (starts at 1)
     pub price_start: u128,   // Starting XLM price in stroops
     pub start_ledger: u32,   // Ledger when round was created
     pub bet_end_ledger: u32, // Ledger when betting closes
+    pub reveal_end_ledger: u32, // Ledger when sealed Precision predictions can no longer be revealed
     pub end_ledger: u32,     // Ledger when round ends (~5s per ledger)
     pub pool_up: i128,       // Total vXLM bet on UP
     pub pool_down: i128,     // Total vXLM bet on DOWN
     pub mode: RoundMode,     // Round mode: UpDown (0) or Precision (1)
+    pub jackpot: i128,       // Swept funds and exit penalties added to this round's winners' payout
+    pub bucket_bounds: Vec<i32>,  // Buckets mode: range boundaries in bps relative to price_start
+    pub bucket_pools: Vec<i128>,  // Buckets mode: total vXLM staked per bucket
+    pub precision_payout: PrecisionPayout,  // Precision mode: payout policy fixed at creation
+    pub tie_rules: PrecisionTieRules,       // Precision mode: tie band and exact-hit bonus fixed at creation
+    pub tournament_id: Option<u32>,         // Tournament the round belongs to; stakes and payouts are in its chips
 }
 
@@ -0,0 +1,161 @@
+//! Replays a price series through the real contract in a Soroban test `Env`.
+
+use hello_world::{VirtualTokenContract, VirtualTokenContractClient};
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+use crate::report::{Report, StrategyReport};
+use crate::strategy::{Rng, Strategy};
+
+/// Parameters for a simulation run
+#[derive(Debug, Clone)]
+pub struct SimConfig {
+    /// Number of bettors created for each strategy
+    pub bettors_per_strategy: u32,
+    /// Stake placed by every bettor each round (in stroops)
+    pub stake: i128,
+    /// Protocol fee passed to `set_fee_bps`
+    pub fee_bps: u32,
+    /// Betting window passed to `set_windows`
+    pub bet_ledgers: u32,
+    /// Run window passed to `set_windows`
+    pub run_ledgers: u32,
+    /// Seed for the random strategy
+    pub seed: u64,
+    /// Strategies to simulate, each with `bettors_per_strategy` bettors
+    pub strategies: Vec<Strategy>,
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        Self {
+            bettors_per_strategy: 5,
+            stake: 10_0000000,
+            fee_bps: 0,
+            bet_ledgers: 6,
+            run_ledgers: 12,
+            seed: 42,
+            strategies: Strategy::ALL.to_vec(),
+        }
+    }
+}
+
+struct Bettor {
+    address: Address,
+    strategy: Strategy,
+}
+
+/// Runs one Up/Down round per consecutive pair of prices and returns the aggregated report
+pub fn run(prices: &[u128], config: &SimConfig) -> Report {
+    // Each run is throwaway; don't write test snapshots to disk
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    // The contract never extends TTLs, so keep entries alive for the whole replay
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = 100_000_000;
+        li.max_entry_ttl = 100_000_000;
+    });
+
+    let contract_id = env.register(VirtualTokenContract, ());
+    let client = VirtualTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.set_windows(&config.bet_ledgers, &config.run_ledgers);
+    client.set_fee_bps(&config.fee_bps);
+
+    let mut bettors = Vec::new();
+    let mut report = Report::default();
+    for &strategy in &config.strategies {
+        report.strategies.push(StrategyReport::new(strategy, config.bettors_per_strategy));
+        for _ in 0..config.bettors_per_strategy {
+            let address = Address::generate(&env);
+            client.mint_initial(&address);
+            bettors.push(Bettor { address, strategy });
+        }
+    }
+
+    let mut rng = Rng::new(config.seed);
+    let mut imbalance_total: u128 = 0;
+
+    for i in 1..prices.len() {
+        let start_price = prices[i - 1];
+        let final_price = prices[i];
+        let history = &prices[..i];
+
+        client.create_round(&start_price, &None);
+
+        let mut stakes = Vec::with_capacity(bettors.len());
+        for bettor in &bettors {
+            let side = bettor.strategy.decide(history, &mut rng);
+            if client.balance(&bettor.address) < config.stake {
+                stakes.push(0);
+                continue;
+            }
+            client.place_bet(&bettor.address, &config.stake, &side);
+            stakes.push(config.stake);
+        }
+
+        let round = client.get_active_round().expect("round was just created");
+        let pool_total = round.pool_up + round.pool_down;
+        if pool_total > 0 {
+            let imbalance = (round.pool_up - round.pool_down).unsigned_abs() * 10_000 / pool_total as u128;
+            imbalance_total += imbalance;
+            report.max_imbalance_bps = report.max_imbalance_bps.max(imbalance as u32);
+            if round.pool_up == 0 || round.pool_down == 0 {
+                report.one_sided_rounds += 1;
+            }
+        }
+
+        env.ledger().with_mut(|li| {
+            li.sequence_number = round.end_ledger;
+        });
+
+        let treasury_before = client.get_treasury();
+        client.resolve_round(&final_price);
+        let fees = client.get_treasury() - treasury_before;
+
+        let mut paid_out: i128 = 0;
+        for (bettor, stake) in bettors.iter().zip(stakes) {
+            let claimed = client.claim_winnings(&bettor.address);
+            paid_out += claimed;
+
+            let entry = report.strategy_mut(bettor.strategy);
+            if stake == 0 {
+                entry.rounds_skipped += 1;
+                continue;
+            }
+            entry.rounds_played += 1;
+            entry.total_staked += stake;
+            entry.total_returned += claimed;
+            if claimed > stake {
+                entry.wins += 1;
+            } else if claimed < stake {
+                entry.losses += 1;
+            }
+        }
+
+        report.rounds += 1;
+        report.total_staked += pool_total;
+        report.total_paid_out += paid_out;
+        report.treasury_revenue += fees;
+        report.dust_lost += pool_total - paid_out - fees;
+    }
+
+    if report.rounds > 0 {
+        report.avg_imbalance_bps = (imbalance_total / report.rounds as u128) as u32;
+    }
+
+    for bettor in &bettors {
+        if client.balance(&bettor.address) < config.stake {
+            report.strategy_mut(bettor.strategy).busted += 1;
+        }
+    }
+
+    report
+}
+
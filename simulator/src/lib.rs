@@ -0,0 +1,24 @@
+//! # Xelma Market Simulator
+//!
+//! Off-chain backtester for the XLM Price Prediction Market.
+//! Replays historical XLM prices and a synthetic population of bettors
+//! through the real `VirtualTokenContract` in a Soroban test `Env`.
+//!
+//! ## Reports
+//! - Per-strategy P&L, ROI and bust count
+//! - Pool imbalance per round
+//! - Dust lost to integer payout rounding
+//! - Treasury revenue for the configured fee
+
+pub mod engine;
+pub mod prices;
+pub mod report;
+pub mod strategy;
+
+#[cfg(test)]
+mod tests;
+
+pub use engine::{run, SimConfig};
+pub use prices::{parse_prices, PriceParseError};
+pub use report::{Report, StrategyReport};
+pub use strategy::Strategy;
@@ -0,0 +1,86 @@
+//! Command-line entry point for the market simulator.
+
+use std::process::ExitCode;
+
+use xelma_simulator::prices::parse_decimal;
+use xelma_simulator::{parse_prices, run, SimConfig, Strategy};
+
+const USAGE: &str = "\
+Usage: xelma-sim <prices.csv> [options]
+
+Options:
+  --bettors <n>       Bettors per strategy (default 5)
+  --strategies <list> Comma-separated strategies to simulate: always-up, momentum,
+                      random, contrarian (default all)
+  --stake <xlm>       Stake per bettor per round, e.g. 10 or 2.5 (default 10)
+  --fee-bps <n>       Protocol fee in basis points (default 0)
+  --bet-ledgers <n>   Betting window in ledgers (default 6)
+  --run-ledgers <n>   Run window in ledgers (default 12)
+  --seed <n>          Seed for the random strategy (default 42)";
+
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn try_main() -> Result<(), String> {
+    let mut args = std::env::args().skip(1);
+    let mut path: Option<String> = None;
+    let mut config = SimConfig::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("missing value for {}", name));
+        match arg.as_str() {
+            "--bettors" => config.bettors_per_strategy = parse_number(&value("--bettors")?)?,
+            "--strategies" => config.strategies = Strategy::parse_list(&value("--strategies")?)?,
+            "--stake" => config.stake = parse_stake(&value("--stake")?)?,
+            "--fee-bps" => config.fee_bps = parse_number(&value("--fee-bps")?)?,
+            "--bet-ledgers" => config.bet_ledgers = parse_number(&value("--bet-ledgers")?)?,
+            "--run-ledgers" => config.run_ledgers = parse_number(&value("--run-ledgers")?)?,
+            "--seed" => config.seed = parse_number(&value("--seed")?)?,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            },
+            other if other.starts_with("--") => return Err(format!("unknown option {}", other)),
+            other => path = Some(other.to_string()),
+        }
+    }
+
+    if config.fee_bps > 10_000 {
+        return Err("--fee-bps must be at most 10000".into());
+    }
+    if config.bet_ledgers == 0 || config.bet_ledgers >= config.run_ledgers {
+        return Err("--bet-ledgers must be positive and less than --run-ledgers".into());
+    }
+
+    let path = path.ok_or("missing prices CSV path")?;
+    let csv = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?;
+    let prices = parse_prices(&csv).map_err(|e| format!("{}: {}", path, e))?;
+
+    let report = run(&prices, &config);
+    print!("{}", report);
+
+    Ok(())
+}
+
+/// Parses a positive XLM amount into stroops
+fn parse_stake(value: &str) -> Result<i128, String> {
+    let stake = parse_decimal(value)
+        .ok()
+        .and_then(|stake| i128::try_from(stake).ok())
+        .unwrap_or(0);
+    if stake <= 0 {
+        return Err(format!("--stake must be a positive XLM amount, got '{}'", value));
+    }
+    Ok(stake)
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("invalid number '{}'", value))
+}
@@ -0,0 +1,92 @@
+//! Historical price loading for the simulator.
+//!
+//! Accepts a CSV where the last column of each row is a decimal XLM price
+//! (e.g. `2024-01-01T00:00:00Z,0.1234`). A non-numeric first row is treated
+//! as a header and skipped. Prices are converted to stroops (7 decimals),
+//! which is the unit `create_round` and `resolve_round` expect.
+
+use std::fmt;
+
+/// Number of decimal places in a stroop-denominated price
+pub const PRICE_DECIMALS: u32 = 7;
+
+/// Error raised when a price row cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceParseError {
+    pub line: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for PriceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for PriceParseError {}
+
+/// Parses CSV text into a series of stroop-denominated prices
+pub fn parse_prices(csv: &str) -> Result<Vec<u128>, PriceParseError> {
+    let mut prices = Vec::new();
+
+    for (index, raw) in csv.lines().enumerate() {
+        let line = index + 1;
+        let row = raw.trim();
+        if row.is_empty() || row.starts_with('#') {
+            continue;
+        }
+
+        let field = row.rsplit(',').next().unwrap_or(row).trim();
+        match parse_decimal(field) {
+            Ok(price) => prices.push(price),
+            // Allow a single header row at the top of the file
+            Err(_) if prices.is_empty() && line == 1 => continue,
+            Err(reason) => return Err(PriceParseError { line, reason }),
+        }
+    }
+
+    if prices.len() < 2 {
+        return Err(PriceParseError {
+            line: 0,
+            reason: "at least two prices are required to simulate a round".into(),
+        });
+    }
+
+    Ok(prices)
+}
+
+/// Converts a decimal string such as `0.2297` into stroops
+pub fn parse_decimal(field: &str) -> Result<u128, String> {
+    let (whole, fraction) = match field.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (field, ""),
+    };
+
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(format!("invalid price '{}'", field));
+    }
+    if fraction.len() > PRICE_DECIMALS as usize {
+        return Err(format!("price '{}' has more than {} decimals", field, PRICE_DECIMALS));
+    }
+    if !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(format!("invalid price '{}'", field));
+    }
+
+    let whole: u128 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| format!("invalid price '{}'", field))? };
+    let mut padded = fraction.to_string();
+    while padded.len() < PRICE_DECIMALS as usize {
+        padded.push('0');
+    }
+    let fraction: u128 = padded.parse().map_err(|_| format!("invalid price '{}'", field))?;
+
+    let price = whole
+        .checked_mul(10u128.pow(PRICE_DECIMALS))
+        .and_then(|p| p.checked_add(fraction))
+        .ok_or_else(|| format!("price '{}' is too large", field))?;
+
+    if price == 0 {
+        return Err("price must be greater than zero".into());
+    }
+
+    Ok(price)
+}
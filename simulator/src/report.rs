@@ -0,0 +1,126 @@
+//! Aggregated simulation results.
+
+use std::fmt;
+
+use crate::strategy::Strategy;
+
+/// Per-strategy outcome across all simulated rounds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrategyReport {
+    pub strategy: Strategy,
+    pub bettors: u32,
+    pub rounds_played: u32,
+    pub rounds_skipped: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub total_staked: i128,
+    pub total_returned: i128,
+    /// Bettors whose balance ended below one stake
+    pub busted: u32,
+}
+
+impl StrategyReport {
+    pub fn new(strategy: Strategy, bettors: u32) -> Self {
+        Self {
+            strategy,
+            bettors,
+            rounds_played: 0,
+            rounds_skipped: 0,
+            wins: 0,
+            losses: 0,
+            total_staked: 0,
+            total_returned: 0,
+            busted: 0,
+        }
+    }
+
+    pub fn net_pnl(&self) -> i128 {
+        self.total_returned - self.total_staked
+    }
+
+    /// Return on stake in basis points
+    pub fn roi_bps(&self) -> i128 {
+        if self.total_staked == 0 {
+            return 0;
+        }
+        self.net_pnl() * 10_000 / self.total_staked
+    }
+}
+
+/// Totals for a whole simulation run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub rounds: u32,
+    pub strategies: Vec<StrategyReport>,
+    pub total_staked: i128,
+    pub total_paid_out: i128,
+    /// Protocol fees credited to the treasury
+    pub treasury_revenue: i128,
+    /// Stakes neither paid out nor collected as fees (payout rounding)
+    pub dust_lost: i128,
+    /// Average |pool_up - pool_down| / (pool_up + pool_down)
+    pub avg_imbalance_bps: u32,
+    pub max_imbalance_bps: u32,
+    /// Rounds where every stake landed on the same side
+    pub one_sided_rounds: u32,
+}
+
+impl Report {
+    pub fn strategy(&self, strategy: Strategy) -> Option<&StrategyReport> {
+        self.strategies.iter().find(|s| s.strategy == strategy)
+    }
+
+    pub(crate) fn strategy_mut(&mut self, strategy: Strategy) -> &mut StrategyReport {
+        self.strategies
+            .iter_mut()
+            .find(|s| s.strategy == strategy)
+            .expect("strategy registered before simulation")
+    }
+}
+
+/// Formats a stroop amount as vXLM with 7 decimals
+fn xlm(amount: i128) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let abs = amount.unsigned_abs();
+    format!("{}{}.{:07}", sign, abs / 10_000_000, abs % 10_000_000)
+}
+
+fn pct(bps: i128) -> String {
+    let sign = if bps < 0 { "-" } else { "" };
+    let abs = bps.unsigned_abs();
+    format!("{}{}.{:02}%", sign, abs / 100, abs % 100)
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Rounds simulated:   {}", self.rounds)?;
+        writeln!(f, "Total staked:       {} vXLM", xlm(self.total_staked))?;
+        writeln!(f, "Total paid out:     {} vXLM", xlm(self.total_paid_out))?;
+        writeln!(f, "Treasury revenue:   {} vXLM", xlm(self.treasury_revenue))?;
+        writeln!(f, "Dust lost:          {} vXLM", xlm(self.dust_lost))?;
+        writeln!(f, "Pool imbalance:     avg {} / max {}", pct(self.avg_imbalance_bps as i128), pct(self.max_imbalance_bps as i128))?;
+        writeln!(f, "One-sided rounds:   {}", self.one_sided_rounds)?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<12} {:>7} {:>7} {:>6} {:>6} {:>20} {:>20} {:>9} {:>6}",
+            "strategy", "bettors", "played", "wins", "losses", "staked", "net P&L", "ROI", "busted"
+        )?;
+        for s in &self.strategies {
+            writeln!(
+                f,
+                "{:<12} {:>7} {:>7} {:>6} {:>6} {:>20} {:>20} {:>9} {:>6}",
+                s.strategy.to_string(),
+                s.bettors,
+                s.rounds_played,
+                s.wins,
+                s.losses,
+                xlm(s.total_staked),
+                xlm(s.net_pnl()),
+                pct(s.roi_bps()),
+                s.busted,
+            )?;
+        }
+        Ok(())
+    }
+}
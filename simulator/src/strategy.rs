@@ -0,0 +1,114 @@
+//! Synthetic bettor strategies.
+
+use std::fmt;
+use std::str::FromStr;
+
+use hello_world::BetSide;
+
+/// Betting strategy followed by a synthetic bettor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Always bets UP
+    AlwaysUp,
+    /// Bets in the direction of the last price move
+    Momentum,
+    /// Bets on a coin flip
+    Random,
+    /// Bets against the direction of the last price move
+    Contrarian,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 4] = [
+        Strategy::AlwaysUp,
+        Strategy::Momentum,
+        Strategy::Random,
+        Strategy::Contrarian,
+    ];
+
+    /// Picks a side given the prices observed so far (last entry is the round's start price)
+    pub fn decide(&self, history: &[u128], rng: &mut Rng) -> BetSide {
+        match self {
+            Strategy::AlwaysUp => BetSide::Up,
+            Strategy::Momentum => last_move(history),
+            Strategy::Random => {
+                if rng.next_u64() & 1 == 0 {
+                    BetSide::Up
+                } else {
+                    BetSide::Down
+                }
+            },
+            Strategy::Contrarian => match last_move(history) {
+                BetSide::Up => BetSide::Down,
+                BetSide::Down => BetSide::Up,
+            },
+        }
+    }
+
+    /// Parses a comma-separated list of strategy names, e.g. `momentum,random`
+    pub fn parse_list(list: &str) -> Result<Vec<Strategy>, String> {
+        let mut strategies = Vec::new();
+        for name in list.split(',').map(str::trim) {
+            let strategy: Strategy = name.parse()?;
+            if strategies.contains(&strategy) {
+                return Err(format!("strategy '{}' listed twice", name));
+            }
+            strategies.push(strategy);
+        }
+        Ok(strategies)
+    }
+}
+
+/// Direction of the most recent price move; flat or unknown history counts as UP
+fn last_move(history: &[u128]) -> BetSide {
+    match history {
+        [.., previous, last] if last < previous => BetSide::Down,
+        _ => BetSide::Up,
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Strategy::AlwaysUp => "always-up",
+            Strategy::Momentum => "momentum",
+            Strategy::Random => "random",
+            Strategy::Contrarian => "contrarian",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Strategy::ALL
+            .iter()
+            .copied()
+            .find(|strategy| strategy.to_string() == s)
+            .ok_or_else(|| format!("unknown strategy '{}'", s))
+    }
+}
+
+/// Small deterministic PRNG (xorshift64*) so runs are reproducible from a seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift must never be seeded with zero
+        Self { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
@@ -0,0 +1,104 @@
+//! Tests for the market simulator.
+
+use crate::prices::parse_decimal;
+use crate::strategy::Rng;
+use crate::{parse_prices, run, SimConfig, Strategy};
+use hello_world::BetSide;
+
+#[test]
+fn test_parse_decimal_to_stroops() {
+    assert_eq!(parse_decimal("0.2297"), Ok(2297000));
+    assert_eq!(parse_decimal("1"), Ok(1_0000000));
+    assert_eq!(parse_decimal(".5"), Ok(5000000));
+    assert!(parse_decimal("0.12345678").is_err());
+    assert!(parse_decimal("abc").is_err());
+    assert!(parse_decimal("0").is_err());
+}
+
+#[test]
+fn test_parse_prices_skips_header_and_uses_last_column() {
+    let csv = "timestamp,price\n2025-01-01,0.25\n\n2025-01-02,0.26\n";
+    assert_eq!(parse_prices(csv), Ok(vec![2500000, 2600000]));
+}
+
+#[test]
+fn test_parse_prices_reports_bad_line() {
+    let csv = "0.25\n0.26\noops\n";
+    let err = parse_prices(csv).unwrap_err();
+    assert_eq!(err.line, 3);
+}
+
+#[test]
+fn test_strategies_follow_price_history() {
+    let mut rng = Rng::new(1);
+    let rising = [100, 110];
+    let falling = [110, 100];
+
+    assert_eq!(Strategy::AlwaysUp.decide(&falling, &mut rng), BetSide::Up);
+    assert_eq!(Strategy::Momentum.decide(&rising, &mut rng), BetSide::Up);
+    assert_eq!(Strategy::Momentum.decide(&falling, &mut rng), BetSide::Down);
+    assert_eq!(Strategy::Contrarian.decide(&rising, &mut rng), BetSide::Down);
+    assert_eq!(Strategy::Contrarian.decide(&falling, &mut rng), BetSide::Up);
+    assert_eq!("momentum".parse(), Ok(Strategy::Momentum));
+}
+
+#[test]
+fn test_parse_strategy_list() {
+    assert_eq!(
+        Strategy::parse_list("momentum, random"),
+        Ok(vec![Strategy::Momentum, Strategy::Random])
+    );
+    assert!(Strategy::parse_list("momentum,momentum").is_err());
+    assert!(Strategy::parse_list("momentum,yolo").is_err());
+    assert!(Strategy::parse_list("").is_err());
+}
+
+#[test]
+fn test_run_only_selected_strategies() {
+    let prices = parse_prices("0.25\n0.26\n0.24\n").unwrap();
+    let config = SimConfig {
+        strategies: vec![Strategy::Contrarian, Strategy::AlwaysUp],
+        ..SimConfig::default()
+    };
+
+    let report = run(&prices, &config);
+
+    let strategies: Vec<Strategy> = report.strategies.iter().map(|s| s.strategy).collect();
+    assert_eq!(strategies, vec![Strategy::Contrarian, Strategy::AlwaysUp]);
+}
+
+#[test]
+fn test_run_conserves_stakes() {
+    let prices = parse_prices(include_str!("../data/xlm_sample.csv")).unwrap();
+    let config = SimConfig {
+        bettors_per_strategy: 2,
+        fee_bps: 200,
+        ..SimConfig::default()
+    };
+
+    let report = run(&prices, &config);
+
+    assert_eq!(report.rounds as usize, prices.len() - 1);
+    assert!(report.total_staked > 0);
+    assert!(report.treasury_revenue > 0);
+    assert_eq!(
+        report.total_staked,
+        report.total_paid_out + report.treasury_revenue + report.dust_lost
+    );
+
+    let strategy_staked: i128 = report.strategies.iter().map(|s| s.total_staked).sum();
+    let strategy_returned: i128 = report.strategies.iter().map(|s| s.total_returned).sum();
+    assert_eq!(strategy_staked, report.total_staked);
+    assert_eq!(strategy_returned, report.total_paid_out);
+}
+
+#[test]
+fn test_run_is_deterministic_for_seed() {
+    let prices = parse_prices("0.25\n0.26\n0.24\n0.25\n").unwrap();
+    let config = SimConfig {
+        bettors_per_strategy: 3,
+        ..SimConfig::default()
+    };
+
+    assert_eq!(run(&prices, &config), run(&prices, &config));
+}